@@ -0,0 +1,107 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::str::FromStr;
+
+/// Reason why a puzzle input could not be parsed. Lines and columns start at 1.
+#[derive(Debug)]
+pub enum ParseError {
+    /// `token` is not valid at this position of the input.
+    UnexpectedToken { line: usize, column: usize, token: String },
+    /// The line ends, or is missing, before `field` could be read.
+    MissingField { line: usize, column: usize, field: &'static str },
+    /// The line is well formed but describes something impossible.
+    Invalid { line: usize, reason: String },
+    /// The input could not be read.
+    Io(io::Error),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { line, column, token } =>
+                write!(f, "line {line}, column {column}: unexpected token '{token}'"),
+            ParseError::MissingField { line, column, field } =>
+                write!(f, "line {line}, column {column}: missing {field}"),
+            ParseError::Invalid { line, reason } => write!(f, "line {line}: {reason}"),
+            ParseError::Io(error) => write!(f, "cannot read the input: {error}"),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(error: io::Error) -> Self {
+        ParseError::Io(error)
+    }
+}
+
+/// A line of the puzzle input together with its number, used to point the
+/// errors at the right place.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Column where `token` starts. `token` should be a slice of the line,
+    /// otherwise the column right after the end of the line is returned.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            self.end()
+        }
+    }
+
+    /// Column right after the last character of the line.
+    pub fn end(&self) -> usize {
+        self.text.chars().count() + 1
+    }
+
+    /// Error for `token`, a slice of the line, not being valid there.
+    pub fn unexpected(&self, token: &str) -> ParseError {
+        self.unexpected_at(self.column(token), token)
+    }
+
+    /// Error for `token` not being valid at `column`.
+    pub fn unexpected_at(&self, column: usize, token: impl Into<String>) -> ParseError {
+        ParseError::UnexpectedToken { line: self.number, column, token: token.into() }
+    }
+
+    /// Error for the line ending before `field`.
+    pub fn missing(&self, field: &'static str) -> ParseError {
+        ParseError::MissingField { line: self.number, column: self.end(), field }
+    }
+
+    /// Returns `token`, or a [`ParseError::MissingField`] when there is none.
+    pub fn field(&self, token: Option<&'a str>, field: &'static str) -> Result<&'a str, ParseError> {
+        token.ok_or_else(|| self.missing(field))
+    }
+
+    /// Parses `token`, a slice of the line, into a `T`.
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.unexpected(token))
+    }
+
+    /// Parses `token` into a `T`, failing if it is missing or not valid.
+    pub fn parse_field<T: FromStr>(&self, token: Option<&'a str>, field: &'static str) -> Result<T, ParseError> {
+        self.parse(self.field(token, field)?)
+    }
+}
+
+/// Iterates over the lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line { number: idx + 1, text })
+}
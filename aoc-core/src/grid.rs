@@ -0,0 +1,360 @@
+use std::fmt::{self, Display, Write};
+use std::ops::{Index, IndexMut};
+
+use crate::{lines, ParseError};
+
+/// Position of a cell in a grid, counted from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Pos { row, column }
+    }
+
+    /// The position `offset` away, or `None` when it would be above the first
+    /// row or left of the first column. Use `Grid::step` to stay within a grid.
+    pub fn offset(self, offset: Offset) -> Option<Self> {
+        Some(Pos {
+            row: self.row.checked_add_signed(offset.rows)?,
+            column: self.column.checked_add_signed(offset.columns)?,
+        })
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.column)
+    }
+}
+
+/// A move from a cell to another, in rows down and columns right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Offset {
+    pub rows: isize,
+    pub columns: isize,
+}
+
+impl Offset {
+    pub const UP: Offset = Offset::new(-1, 0);
+    pub const DOWN: Offset = Offset::new(1, 0);
+    pub const LEFT: Offset = Offset::new(0, -1);
+    pub const RIGHT: Offset = Offset::new(0, 1);
+    pub const UP_LEFT: Offset = Offset::new(-1, -1);
+    pub const UP_RIGHT: Offset = Offset::new(-1, 1);
+    pub const DOWN_LEFT: Offset = Offset::new(1, -1);
+    pub const DOWN_RIGHT: Offset = Offset::new(1, 1);
+
+    /// The 4 moves to a cell sharing a side.
+    pub const ORTHOGONAL: [Offset; 4] = [Offset::UP, Offset::DOWN, Offset::LEFT, Offset::RIGHT];
+    /// The 8 moves to a cell sharing a side or a corner.
+    pub const ALL: [Offset; 8] = [
+        Offset::UP, Offset::DOWN, Offset::LEFT, Offset::RIGHT,
+        Offset::UP_LEFT, Offset::UP_RIGHT, Offset::DOWN_LEFT, Offset::DOWN_RIGHT,
+    ];
+
+    pub const fn new(rows: isize, columns: isize) -> Self {
+        Offset { rows, columns }
+    }
+}
+
+/// A rectangle of cells, stored row after row. Positions outside of it give
+/// `None` rather than panicking, except when indexing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns and `height` rows, all holding `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// A grid holding `cell(pos)` at each position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| Pos { row, column }))
+            .map(&mut cell)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /// A grid of `rows`, or `None` if they are not all as long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Reads a grid with a character per cell and a line per row, turning each
+    /// character into a cell with `cell`. Fails on the characters `cell`
+    /// refuses and on lines shorter or longer than the first one.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut rows = vec![];
+        for line in lines(input) {
+            let mut row = vec![];
+            for (column, ch) in (1..).zip(line.text.chars()) {
+                row.push(cell(ch).ok_or_else(|| line.unexpected_at(column, ch))?);
+            }
+            if let Some(first) = rows.first().map(Vec::len).filter(|&first| first != row.len()) {
+                return Err(ParseError::Invalid {
+                    line: line.number,
+                    reason: format!("expected {first} cells like the first line, found {}", row.len()),
+                });
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).unwrap())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.row * self.width + pos.column])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self.cells[pos.row * self.width + pos.column])
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Pos { row, column }))
+    }
+
+    /// Every cell, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks` panics on a width of 0
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// The cells of `column`, from the top.
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(column < self.width, "column {column} out of a grid {} wide", self.width);
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// The position `offset` away from `pos`, if it is in the grid.
+    pub fn step(&self, pos: Pos, offset: Offset) -> Option<Pos> {
+        pos.offset(offset).filter(|&next| self.contains(next))
+    }
+
+    /// The positions sharing a side with `pos`: up, down, left and right.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Offset::ORTHOGONAL.into_iter().filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// The positions sharing a side or a corner with `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Offset::ALL.into_iter().filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// The positions from `pos`, excluded, moving by `offset` until leaving
+    /// the grid.
+    pub fn ray(&self, pos: Pos, offset: Offset) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, offset), move |&next| self.step(next, offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Adds `count` rows holding `value` at the bottom.
+    pub fn add_rows(&mut self, count: usize, value: T) where T: Clone {
+        self.cells.resize(self.cells.len() + count * self.width, value);
+        self.height += count;
+    }
+
+    /// The grid surrounded by `margin` rows and columns holding `value` on
+    /// every side, so that `pos` becomes `pos` moved by `margin` down and right.
+    pub fn padded(&self, margin: usize, value: T) -> Self where T: Clone {
+        self.extended([margin; 4], value)
+    }
+
+    /// The grid with `top`, `bottom`, `left` and `right` rows and columns
+    /// holding `value` added on these sides, so that `pos` becomes `pos` moved
+    /// by `top` down and `left` right.
+    pub fn extended(&self, [top, bottom, left, right]: [usize; 4], value: T) -> Self where T: Clone {
+        let mut extended = Grid::new(left + self.width + right, top + self.height + bottom, value);
+        for (pos, cell) in self.enumerate() {
+            extended[Pos::new(pos.row + top, pos.column + left)] = cell.clone();
+        }
+        extended
+    }
+
+    /// Draws the grid with a character per cell.
+    pub fn render<'a>(&'a self, cell: impl Fn(&T) -> char + 'a) -> impl Display + 'a {
+        Render { grid: self, cell }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{pos} out of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos} out of a {width}x{height} grid"))
+    }
+}
+
+struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, row) in self.grid.rows().enumerate() {
+            if idx > 0 {writeln!(f)?;}
+            for cell in row {
+                f.write_char((self.cell)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes each cell, a row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {writeln!(f)?;}
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |ch| ch.to_digit(10))
+    }
+
+    #[test]
+    fn rows_must_be_as_long() {
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.row(1), [3, 4]);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+        let empty = Grid::<u8>::from_rows(vec![]).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.rows().len(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(digits("12\n34").unwrap(), Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap());
+        assert!(matches!(digits("12\n3x"), Err(ParseError::UnexpectedToken { line: 2, column: 2, token }) if token == "x"));
+        assert!(matches!(digits("12\n345"), Err(ParseError::Invalid { line: 2, reason }) if reason == "expected 2 cells like the first line, found 3"));
+        assert!(matches!(digits("12\n\n34"), Err(ParseError::Invalid { line: 2, .. })));
+        assert!(digits("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn steps_stay_in_the_grid() {
+        let grid = Grid::new(3, 2, 0);
+        let corner = Pos::new(0, 0);
+        assert_eq!(grid.step(corner, Offset::UP), None);
+        assert_eq!(grid.step(corner, Offset::LEFT), None);
+        assert_eq!(grid.step(corner, Offset::DOWN_RIGHT), Some(Pos::new(1, 1)));
+        assert_eq!(grid.step(Pos::new(1, 2), Offset::DOWN), None);
+        assert_eq!(grid.step(Pos::new(1, 2), Offset::RIGHT), None);
+        assert_eq!(grid.neighbours(corner).collect::<Vec<_>>(), [Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+    }
+
+    #[test]
+    fn rays_stop_at_the_edge() {
+        let grid = Grid::new(4, 3, 0);
+        assert_eq!(grid.ray(Pos::new(0, 1), Offset::RIGHT).collect::<Vec<_>>(), [Pos::new(0, 2), Pos::new(0, 3)]);
+        assert_eq!(grid.ray(Pos::new(2, 0), Offset::UP_RIGHT).collect::<Vec<_>>(), [Pos::new(1, 1), Pos::new(0, 2)]);
+        assert_eq!(grid.ray(Pos::new(0, 0), Offset::UP).count(), 0);
+        assert_eq!(grid.ray(Pos::new(2, 3), Offset::DOWN_RIGHT).count(), 0);
+    }
+
+    #[test]
+    fn padded_and_extended() {
+        let grid = digits("12\n34").unwrap();
+        let padded = grid.padded(1, 0);
+        assert_eq!(padded.to_string(), "0000\n0120\n0340\n0000");
+        assert_eq!(grid.extended([0, 1, 2, 0], 9).to_string(), "9912\n9934\n9999");
+        assert_eq!(grid.padded(0, 0), grid);
+    }
+
+    #[test]
+    fn added_rows_go_at_the_bottom() {
+        let mut grid = digits("12").unwrap();
+        grid.add_rows(2, 7);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.to_string(), "12\n77\n77");
+    }
+
+    #[test]
+    fn columns_from_the_top() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), [6, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of a grid 3 wide")]
+    fn columns_outside_of_the_grid() {
+        let _ = Grid::new(3, 1, 0).column(3);
+    }
+
+    #[test]
+    fn render_a_character_per_cell() {
+        let grid = digits("10\n01").unwrap();
+        assert_eq!(grid.render(|&cell| if cell == 1 {'#'} else {'.'}).to_string(), "#.\n.#");
+        assert_eq!(Grid::new(2, 0, 0).render(|_| '#').to_string(), "");
+    }
+}
@@ -0,0 +1,36 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Puzzle input read when no other file is given.
+pub const DEFAULT_INPUT: &str = "./input.txt";
+
+/// Reads the whole puzzle input from `reader`.
+pub fn read_from(mut reader: impl Read) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Reads the puzzle input stored in `filename`.
+///
+/// Panics if the file cannot be read, since none of the solutions can do
+/// anything useful without their input.
+pub fn read_input<P>(filename: P) -> String
+where P: AsRef<Path>, {
+    let filename = filename.as_ref();
+    fs::read_to_string(filename)
+        .unwrap_or_else(|_| panic!("File '{}' should be accesible", filename.display()))
+}
+
+/// Returns the input file given as the first command line argument, or
+/// `./input.txt` when none is given.
+pub fn input_path() -> String {
+    let mut args = env::args().skip(1);
+    let input = args.next().unwrap_or_else(|| DEFAULT_INPUT.to_string());
+    if args.next().is_some() {
+        panic!("Should provide at most one argument");
+    }
+    input
+}
@@ -0,0 +1,278 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, Sub};
+
+/// The integer types an interval can hold.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+
+    /// The integer right after `self`, or `None` if `self` is the largest.
+    fn next(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ONE: Self = 1;
+
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The integers from `start` to `end`, both included. An interval is never
+/// empty: operations that could give an empty one return an `Option` or a
+/// possibly empty list instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// Returns `start..=end`, or `None` when `end` comes before `start`.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Number of integers in the interval. Overflows when `T` cannot hold
+    /// it, such as for every `u32` from 0.
+    pub fn len(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every integer of `other` is in `self`.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether some integer is in both `self` and `other`.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The integers in both `self` and `other`, if there is any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The smallest interval holding both `self` and `other`.
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval { start: self.start.min(other.start), end: self.end.max(other.end) }
+    }
+
+    /// The integers in `self` or in `other`, if they form a single interval,
+    /// that is when the intervals overlap or are next to each other.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        // An interval reaching the largest integer has nothing after it
+        let touches = |a: &Self, b: &Self| a.end.next().is_none_or(|after| b.start <= after);
+        (touches(self, other) && touches(other, self))
+            .then(|| self.hull(other))
+    }
+
+    /// The integers in `self` but not in `other`: none, or the intervals
+    /// before and after `other`.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        // Both bounds of `other` are within `self` when there is something
+        // before or after it, so the integers next to them exist
+        let before = (self.start < other.start).then(|| Interval { start: self.start, end: other.start - T::ONE });
+        let after = other.end.next()
+            .filter(|&start| start <= self.end)
+            .map(|start| Interval { start, end: self.end });
+        before.into_iter().chain(after).collect()
+    }
+
+    /// Merges `intervals` into the fewest intervals covering the same integers,
+    /// sorted and neither overlapping nor next to each other.
+    pub fn merge(intervals: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut intervals: Vec<_> = intervals.into_iter().collect();
+        intervals.sort_unstable();
+
+        let mut merged: Vec<Self> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut().and_then(|last| last.union(&interval).map(|union| (last, union))) {
+                Some((last, union)) => *last = union,
+                None => merged.push(interval),
+            }
+        }
+        merged
+    }
+
+    /// Splits the integers covered by `intervals` into sorted intervals, each
+    /// with the number of `intervals` covering all of it.
+    pub fn coverage(intervals: impl IntoIterator<Item = Self>) -> Vec<(Self, usize)> {
+        // Coverage goes up right before each start, and down right after each
+        // end. A boundary is an integer and whether it is after that integer,
+        // so that no boundary falls past the largest one.
+        let mut changes = vec![];
+        for interval in intervals {
+            changes.push(((interval.start, false), 1));
+            changes.push(((interval.end, true), -1));
+        }
+        changes.sort_unstable();
+
+        let mut coverage = vec![];
+        let mut count: isize = 0;
+        for (idx, &((position, after), change)) in changes.iter().enumerate() {
+            count += change;
+            let Some(&((next, next_after), _)) = changes.get(idx + 1) else {break};
+            if count == 0 {
+                continue;
+            }
+            // The integers between this boundary and the next one
+            let start = if after {position.next()} else {Some(position)};
+            let Some(start) = start else {continue};
+            let end = if next_after {Some(next)} else {(start < next).then(|| next - T::ONE)};
+            if let Some(end) = end.filter(|&end| start <= end) {
+                coverage.push((Interval { start, end }, count as usize));
+            }
+        }
+        coverage
+    }
+
+    /// The integers covered by at least `min_count` of `intervals`, as merged
+    /// intervals.
+    pub fn covered_by(intervals: impl IntoIterator<Item = Self>, min_count: usize) -> Vec<Self> {
+        Interval::merge(Interval::coverage(intervals).into_iter()
+            .filter(|&(_, count)| count >= min_count)
+            .map(|(interval, _)| interval))
+    }
+
+    /// The integers of `within` covered by none of `intervals`, as sorted
+    /// intervals.
+    pub fn gaps(intervals: impl IntoIterator<Item = Self>, within: Self) -> Vec<Self> {
+        let mut gaps = vec![within];
+        for interval in Interval::merge(intervals) {
+            let Some(last) = gaps.pop() else {break};
+            gaps.extend(last.difference(&interval));
+        }
+        gaps
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: u32, end: u32) -> Interval<u32> {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn never_empty() {
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(interval(2, 2).len(), 1);
+        assert_eq!(interval(2, 5).len(), 4);
+        assert_eq!(interval(2, 5).to_string(), "2-5");
+    }
+
+    #[test]
+    fn intersection() {
+        assert_eq!(interval(1, 5).intersection(&interval(3, 8)), Some(interval(3, 5)));
+        assert_eq!(interval(1, 5).intersection(&interval(5, 8)), Some(interval(5, 5)));
+        assert_eq!(interval(1, 5).intersection(&interval(6, 8)), None);
+        assert_eq!(interval(1, 9).intersection(&interval(3, 4)), Some(interval(3, 4)));
+    }
+
+    #[test]
+    fn union_of_overlapping_or_adjacent_intervals() {
+        assert_eq!(interval(1, 5).union(&interval(3, 8)), Some(interval(1, 8)));
+        assert_eq!(interval(1, 5).union(&interval(6, 8)), Some(interval(1, 8)));
+        assert_eq!(interval(6, 8).union(&interval(1, 5)), Some(interval(1, 8)));
+        assert_eq!(interval(1, 5).union(&interval(7, 8)), None);
+        assert_eq!(interval(7, 8).union(&interval(1, 5)), None);
+    }
+
+    #[test]
+    fn union_up_to_the_largest_integer() {
+        assert_eq!(interval(u32::MAX, u32::MAX).union(&interval(1, 2)), None);
+        assert_eq!(interval(1, 2).union(&interval(u32::MAX, u32::MAX)), None);
+        assert_eq!(interval(5, u32::MAX).union(&interval(1, 4)), Some(interval(1, u32::MAX)));
+        assert_eq!(interval(0, u32::MAX).union(&interval(0, u32::MAX)), Some(interval(0, u32::MAX)));
+        assert_eq!(Interval::new(i8::MIN, -1).unwrap().union(&Interval::new(0, i8::MAX).unwrap()),
+            Interval::new(i8::MIN, i8::MAX));
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(interval(1, 9).difference(&interval(3, 5)), [interval(1, 2), interval(6, 9)]);
+        assert_eq!(interval(1, 9).difference(&interval(1, 5)), [interval(6, 9)]);
+        assert_eq!(interval(1, 9).difference(&interval(5, 12)), [interval(1, 4)]);
+        assert_eq!(interval(1, 9).difference(&interval(0, 9)), []);
+        assert_eq!(interval(1, 9).difference(&interval(10, 12)), [interval(1, 9)]);
+        assert_eq!(interval(0, u32::MAX).difference(&interval(0, u32::MAX - 1)), [interval(u32::MAX, u32::MAX)]);
+        assert_eq!(interval(0, u32::MAX).difference(&interval(1, u32::MAX)), [interval(0, 0)]);
+        assert_eq!(interval(5, u32::MAX).difference(&interval(u32::MAX, u32::MAX)), [interval(5, u32::MAX - 1)]);
+    }
+
+    #[test]
+    fn merge() {
+        let merged = Interval::merge([interval(10, 12), interval(1, 3), interval(4, 5), interval(2, 3), interval(7, 8)]);
+        assert_eq!(merged, [interval(1, 5), interval(7, 8), interval(10, 12)]);
+        assert_eq!(Interval::merge([interval(1, 9), interval(2, 3)]), [interval(1, 9)]);
+        assert_eq!(Interval::merge([interval(u32::MAX, u32::MAX), interval(0, u32::MAX - 1)]), [interval(0, u32::MAX)]);
+        assert_eq!(Interval::merge([]), Vec::<Interval<u32>>::new());
+    }
+
+    #[test]
+    fn coverage() {
+        let coverage = Interval::coverage([interval(1, 5), interval(3, 8), interval(3, 3), interval(10, 10)]);
+        assert_eq!(coverage, [
+            (interval(1, 2), 1),
+            (interval(3, 3), 3),
+            (interval(4, 5), 2),
+            (interval(6, 8), 1),
+            (interval(10, 10), 1),
+        ]);
+        assert_eq!(Interval::coverage([interval(1, 2), interval(3, 4)]), [(interval(1, 2), 1), (interval(3, 4), 1)]);
+        assert_eq!(Interval::<u32>::coverage([]), []);
+    }
+
+    #[test]
+    fn coverage_up_to_the_largest_integer() {
+        let coverage = Interval::coverage([interval(u32::MAX, u32::MAX), interval(1, 2), interval(2, u32::MAX)]);
+        assert_eq!(coverage, [
+            (interval(1, 1), 1),
+            (interval(2, 2), 2),
+            (interval(3, u32::MAX - 1), 1),
+            (interval(u32::MAX, u32::MAX), 2),
+        ]);
+        let coverage = Interval::coverage([Interval::new(i8::MIN, i8::MAX).unwrap(), Interval::new(i8::MIN, i8::MIN).unwrap()]);
+        assert_eq!(coverage, [(Interval::new(i8::MIN, i8::MIN).unwrap(), 2), (Interval::new(i8::MIN + 1, i8::MAX).unwrap(), 1)]);
+    }
+
+    #[test]
+    fn covered_by_and_gaps() {
+        let intervals = [interval(1, 5), interval(3, 8), interval(7, 9), interval(12, 12)];
+        assert_eq!(Interval::covered_by(intervals, 2), [interval(3, 5), interval(7, 8)]);
+        assert_eq!(Interval::covered_by(intervals, 1), [interval(1, 9), interval(12, 12)]);
+        assert_eq!(Interval::gaps(intervals, interval(0, 15)), [interval(0, 0), interval(10, 11), interval(13, 15)]);
+        assert_eq!(Interval::gaps(intervals, interval(2, 9)), []);
+        assert_eq!(Interval::gaps([interval(u32::MAX, u32::MAX), interval(1, 2)], interval(1, u32::MAX)),
+            [interval(3, u32::MAX - 1)]);
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by every day of the Advent of Code 2022: loading the
+//! puzzle input, reporting the results of the solutions and the data
+//! structures several days need.
+
+mod error;
+mod grid;
+mod input;
+mod interval;
+mod report;
+mod solution;
+
+pub use error::{lines, Line, ParseError};
+pub use grid::{Grid, Offset, Pos};
+pub use input::{input_path, read_from, read_input, DEFAULT_INPUT};
+pub use interval::{Integer, Interval};
+pub use report::report;
+pub use solution::{answer, run, solve, Run, Solution, Unsolved};
@@ -0,0 +1,6 @@
+use std::fmt::Display;
+
+/// Prints one result of a solution next to its description.
+pub fn report(description: &str, answer: impl Display) {
+    println!("{description} {answer}");
+}
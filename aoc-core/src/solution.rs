@@ -0,0 +1,76 @@
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+use crate::{read_from, ParseError};
+
+/// A day of the Advent of Code, split into parsing its input and solving
+/// each of its two parts.
+pub trait Solution {
+    /// The puzzle input once parsed, shared by both parts.
+    type Input;
+    type Part1: Display + 'static;
+    type Part2: Display + 'static;
+
+    /// Parses the text of the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Reads the puzzle input from `reader` and parses it.
+    fn parse_reader(reader: impl Read) -> Result<Self::Input, ParseError> {
+        Self::parse(&read_from(reader)?)
+    }
+}
+
+/// Answer of a part that has not been solved, like the second part of day 25
+/// which has no puzzle at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// Formats the answer of a part, or returns `None` if the part is [`Unsolved`].
+pub fn answer<T: Display + 'static>(answer: T) -> Option<String> {
+    if (&answer as &dyn Any).is::<Unsolved>() {
+        return None;
+    }
+    Some(answer.to_string())
+}
+
+/// Answer of a part, with the time taken to parse the input and to solve the part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Run {
+    /// The formatted answer, or `None` if there is no solution for that part.
+    pub answer: Option<String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses the puzzle text `input` with `S` and solves `part` of it, timing both.
+pub fn run<S: Solution>(part: u8, input: &str) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => answer(S::part1(&input)),
+        2 => answer(S::part2(&input)),
+        _ => None,
+    };
+    let solve_time = start.elapsed();
+
+    Ok(Run { answer, parse_time, solve_time })
+}
+
+/// Parses the puzzle text `input` with `S` and solves `part` of it, returning the formatted
+/// answer or `None` if there is no solution for that part.
+pub fn solve<S: Solution>(part: u8, input: &str) -> Result<Option<String>, ParseError> {
+    run::<S>(part, input).map(|run| run.answer)
+}
@@ -0,0 +1,63 @@
+//! Measures parsing and each part of every day on its personal input.
+
+use std::any::TypeId;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use aoc_core::{Solution, Unsolved};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks `S` as the group `dayN`, with one benchmark for parsing and one
+/// for each solved part. Days without an `input.txt` are skipped.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+        .join(format!("day{day}")).join("input.txt");
+    let Ok(input) = fs::read_to_string(&path) else {return};
+    let parsed = S::parse(&input)
+        .unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+
+    let mut group = c.benchmark_group(format!("day{day}"));
+    // The slowest days take seconds per run, far too long for the default 100 samples
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    if TypeId::of::<S::Part1>() != TypeId::of::<Unsolved>() {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    }
+    if TypeId::of::<S::Part2>() != TypeId::of::<Unsolved>() {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, 1);
+    bench_day::<day2::Day2>(c, 2);
+    bench_day::<day3::Day3>(c, 3);
+    bench_day::<day4::Day4>(c, 4);
+    bench_day::<day5::Day5>(c, 5);
+    bench_day::<day6::Day6>(c, 6);
+    bench_day::<day7::Day7>(c, 7);
+    bench_day::<day8::Day8>(c, 8);
+    bench_day::<day9::Day9>(c, 9);
+    bench_day::<day10::Day10>(c, 10);
+    bench_day::<day11::Day11>(c, 11);
+    bench_day::<day12::Day12>(c, 12);
+    bench_day::<day13::Day13>(c, 13);
+    bench_day::<day14::Day14>(c, 14);
+    bench_day::<day15::Day15>(c, 15);
+    bench_day::<day16::Day16>(c, 16);
+    bench_day::<day17::Day17>(c, 17);
+    bench_day::<day18::Day18>(c, 18);
+    bench_day::<day19::Day19>(c, 19);
+    bench_day::<day20::Day20>(c, 20);
+    bench_day::<day21::Day21>(c, 21);
+    bench_day::<day22::Day22>(c, 22);
+    bench_day::<day23::Day23>(c, 23);
+    bench_day::<day24::Day24>(c, 24);
+    bench_day::<day25::Day25>(c, 25);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::website::{Http, HttpError, Website};
+
+/// Reason why a puzzle input could not be fetched.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The website refused to give the input or could not be reached.
+    Http(HttpError),
+    /// The input could not be written to the cache.
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Http(error) => write!(f, "cannot download the input: {error}"),
+            FetchError::Io(error) => write!(f, "cannot write the input: {error}"),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Http(error) => Some(error),
+            FetchError::Io(error) => Some(error),
+        }
+    }
+}
+
+impl From<HttpError> for FetchError {
+    fn from(error: HttpError) -> Self {
+        FetchError::Http(error)
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(error: io::Error) -> Self {
+        FetchError::Io(error)
+    }
+}
+
+/// Where a fetched input can be found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input has just been downloaded to this file.
+    Downloaded(PathBuf),
+    /// The input was already in this file, so it has not been downloaded.
+    Cached(PathBuf),
+}
+
+/// Per-user directory where the inputs are cached, or `None` when the system
+/// has none.
+pub fn cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("aoc-2022"))
+}
+
+/// File of the cache in `cache` holding the input of `day`.
+pub fn cached_input(cache: &Path, day: u8) -> PathBuf {
+    cache.join(format!("day{day}")).join("input.txt")
+}
+
+/// Downloads the input of `day` from `website` into `cache`. An input that is
+/// already cached is kept as it is, without any request, unless `force` is set.
+pub fn fetch(website: &Website<impl Http>, cache: &Path, day: u8, force: bool) -> Result<Fetched, FetchError> {
+    let path = cached_input(cache, day);
+    if path.exists() && !force {
+        return Ok(Fetched::Cached(path));
+    }
+
+    // Download before touching the cache, so a failure leaves it as it was
+    let input = website.input(day)?;
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, input)?;
+
+    Ok(Fetched::Downloaded(path))
+}
@@ -0,0 +1,40 @@
+use aoc_core::{ParseError, Run};
+
+/// Runs `part` of `day` on the puzzle text `input` and returns its answer,
+/// or `None` when there is no solution for that part.
+pub fn solve(day: u8, part: u8, input: &str) -> Result<Option<String>, ParseError> {
+    run(day, part, input).map(|run| run.answer)
+}
+
+/// Runs `part` of `day` on the puzzle text `input`, timing the parsing and the
+/// solving separately.
+pub fn run(day: u8, part: u8, input: &str) -> Result<Run, ParseError> {
+    match day {
+        1 => aoc_core::run::<day1::Day1>(part, input),
+        2 => aoc_core::run::<day2::Day2>(part, input),
+        3 => aoc_core::run::<day3::Day3>(part, input),
+        4 => aoc_core::run::<day4::Day4>(part, input),
+        5 => aoc_core::run::<day5::Day5>(part, input),
+        6 => aoc_core::run::<day6::Day6>(part, input),
+        7 => aoc_core::run::<day7::Day7>(part, input),
+        8 => aoc_core::run::<day8::Day8>(part, input),
+        9 => aoc_core::run::<day9::Day9>(part, input),
+        10 => aoc_core::run::<day10::Day10>(part, input),
+        11 => aoc_core::run::<day11::Day11>(part, input),
+        12 => aoc_core::run::<day12::Day12>(part, input),
+        13 => aoc_core::run::<day13::Day13>(part, input),
+        14 => aoc_core::run::<day14::Day14>(part, input),
+        15 => aoc_core::run::<day15::Day15>(part, input),
+        16 => aoc_core::run::<day16::Day16>(part, input),
+        17 => aoc_core::run::<day17::Day17>(part, input),
+        18 => aoc_core::run::<day18::Day18>(part, input),
+        19 => aoc_core::run::<day19::Day19>(part, input),
+        20 => aoc_core::run::<day20::Day20>(part, input),
+        21 => aoc_core::run::<day21::Day21>(part, input),
+        22 => aoc_core::run::<day22::Day22>(part, input),
+        23 => aoc_core::run::<day23::Day23>(part, input),
+        24 => aoc_core::run::<day24::Day24>(part, input),
+        25 => aoc_core::run::<day25::Day25>(part, input),
+        _ => Ok(Run::default()),
+    }
+}
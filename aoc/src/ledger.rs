@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::website::{Http, HttpError, Verdict, Website};
+
+/// Reason why the ledger could not be loaded or saved.
+#[derive(Debug)]
+pub enum LedgerError {
+    Io(io::Error),
+    /// The ledger file is not a valid ledger.
+    Invalid(String),
+}
+
+impl Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LedgerError::Io(error) => write!(f, "cannot access the ledger: {error}"),
+            LedgerError::Invalid(error) => write!(f, "invalid ledger: {error}"),
+        }
+    }
+}
+
+impl Error for LedgerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LedgerError::Io(error) => Some(error),
+            LedgerError::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for LedgerError {
+    fn from(error: io::Error) -> Self {
+        LedgerError::Io(error)
+    }
+}
+
+/// An answer sent to the website, with its reply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub message: String,
+}
+
+impl Submission {
+    /// Whether this submission proves `answer` wrong, either because it is the
+    /// same wrong answer or because it is past a bound given by the website.
+    fn rules_out(&self, answer: &str) -> bool {
+        if !self.verdict.is_wrong() {
+            return false;
+        }
+        if self.answer == answer {
+            return true;
+        }
+        let (Ok(bound), Ok(answer)) = (self.answer.parse::<i64>(), answer.parse::<i64>()) else {
+            return false;
+        };
+        match self.verdict {
+            Verdict::TooHigh => answer >= bound,
+            Verdict::TooLow => answer <= bound,
+            _ => false,
+        }
+    }
+}
+
+/// What happened to an answer given to [`Ledger::submit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer is already known to be the right one, so it was not sent.
+    AlreadyCorrect,
+    /// The answer is known to be wrong because of this submission, so it was
+    /// not sent.
+    KnownWrong(Submission),
+    /// The answer was sent and the website replied this.
+    Submitted(Submission),
+}
+
+/// Every answer submitted so far, kept on disk so that a wrong answer is never
+/// sent twice.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+/// Per-user file where the ledger is kept, or `None` when the system has no
+/// data directory.
+pub fn ledger_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("aoc-2022").join("ledger.toml"))
+}
+
+impl Ledger {
+    /// Loads the ledger from `path`, or returns an empty one if there is no
+    /// such file yet.
+    pub fn load(path: &Path) -> Result<Self, LedgerError> {
+        match fs::read_to_string(path) {
+            Ok(ledger) => toml::from_str(&ledger).map_err(|error| LedgerError::Invalid(error.to_string())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), LedgerError> {
+        let ledger = toml::to_string(self).map_err(|error| LedgerError::Invalid(error.to_string()))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, ledger)?;
+        Ok(())
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// The right answer of `part` of `day`, if it has been found.
+    pub fn correct(&self, day: u8, part: u8) -> Option<&str> {
+        self.submissions.iter()
+            .find(|submission| submission.day == day && submission.part == part && submission.verdict == Verdict::Correct)
+            .map(|submission| submission.answer.as_str())
+    }
+
+    /// The submission proving `answer` wrong for `part` of `day`, if any.
+    pub fn known_wrong(&self, day: u8, part: u8, answer: &str) -> Option<&Submission> {
+        self.submissions.iter()
+            .find(|submission| submission.day == day && submission.part == part && submission.rules_out(answer))
+    }
+
+    /// Submits `answer` to `part` of `day` on `website` and records the reply,
+    /// unless the ledger already knows whether it is right.
+    pub fn submit(&mut self, website: &Website<impl Http>, day: u8, part: u8, answer: &str) -> Result<Outcome, HttpError> {
+        match self.correct(day, part) {
+            Some(correct) if correct == answer => return Ok(Outcome::AlreadyCorrect),
+            // Only one answer is right, any other is wrong
+            Some(correct) => return Ok(Outcome::KnownWrong(Submission {
+                day,
+                part,
+                answer: answer.to_string(),
+                verdict: Verdict::Wrong,
+                message: format!("The right answer is {correct}."),
+            })),
+            None => (),
+        }
+        if let Some(submission) = self.known_wrong(day, part, answer) {
+            return Ok(Outcome::KnownWrong(submission.clone()));
+        }
+
+        let reply = website.submit(day, part, answer)?;
+        let submission = Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: reply.verdict,
+            message: reply.message,
+        };
+        self.submissions.push(submission.clone());
+        Ok(Outcome::Submitted(submission))
+    }
+}
@@ -0,0 +1,9 @@
+//! Runs the solution of any day of the Advent of Code 2022 on a puzzle input,
+//! fetches the puzzle inputs from the website and submits the answers to it.
+
+pub mod cache;
+mod days;
+pub mod ledger;
+pub mod website;
+
+pub use days::{run, solve};
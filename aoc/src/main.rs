@@ -0,0 +1,270 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use aoc::cache::{self, Fetched};
+use aoc::ledger::{self, Ledger, Outcome};
+use aoc::website::{self, Ureq, Verdict, Website};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+/// Runs the Advent of Code 2022 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Directory where the fetched inputs are cached. Defaults to a per-user
+    /// cache directory
+    #[arg(long, global = true, env = "AOC_CACHE_DIR")]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One line per part, for humans
+    Text,
+    /// One JSON object per line and part, with the answer and the parse and
+    /// solve times in nanoseconds
+    Json,
+}
+
+/// A line printed by `--format json`.
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    parse_ns: u128,
+    solve_ns: u128,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of one day and prints its answers
+    Run {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part of the puzzle to run. Both parts are run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file, or `-` to read it from stdin. Defaults to
+        /// `dayN/input.txt`, or to the fetched input when there is none
+        #[arg(long)]
+        input: Option<String>,
+
+        /// How the answers are printed
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Downloads the puzzle input of one day into the cache
+    Fetch {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Downloads the input again even if it is already cached
+        #[arg(long)]
+        force: bool,
+
+        /// Value of the `session` cookie of the website once logged in
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+    },
+    /// Submits the answer found by the solution of one part to the website
+    Submit {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part of the puzzle to submit
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Puzzle input file, or `-` to read it from stdin. Defaults to
+        /// `dayN/input.txt`, or to the fetched input when there is none
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Value of the `session` cookie of the website once logged in
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+
+        /// File recording every submitted answer. Defaults to a per-user data
+        /// directory
+        #[arg(long, env = "AOC_LEDGER")]
+        ledger: Option<PathBuf>,
+    },
+}
+
+/// Input of `day` when none is given: the one of the day's crate if there is
+/// one, otherwise the one in the cache.
+fn default_input(day: u8, cache: Option<&Path>) -> String {
+    let local = PathBuf::from(format!("day{day}/input.txt"));
+    match cache {
+        Some(cache) if !local.exists() => cache::cached_input(cache, day).display().to_string(),
+        _ => local.display().to_string(),
+    }
+}
+
+/// Reads the puzzle input of `day` from `input`, returning it with its path.
+fn read_puzzle(day: u8, input: Option<String>, cache: Option<&Path>) -> Option<(String, String)> {
+    let path = input.unwrap_or_else(|| default_input(day, cache));
+    let input = if path == "-" {
+        aoc_core::read_from(io::stdin().lock())
+    } else {
+        fs::read_to_string(&path)
+    };
+    match input {
+        Ok(input) => Some((path, input)),
+        Err(error) => {
+            eprintln!("Cannot read the input '{path}': {error}");
+            None
+        }
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<String>, format: Format, cache: Option<&Path>) -> ExitCode {
+    let Some((path, input)) = read_puzzle(day, input, cache) else {
+        return ExitCode::FAILURE;
+    };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for current_part in parts {
+        let run = match aoc::run(day, current_part, &input) {
+            Ok(run) => run,
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let Some(answer) = run.answer else {
+            // Only fail when the missing part was asked for explicitly
+            if part.is_some() {
+                eprintln!("Day {day} part {current_part} has no solution");
+                return ExitCode::FAILURE;
+            }
+            continue;
+        };
+
+        match format {
+            Format::Text => {
+                let duration = run.parse_time + run.solve_time;
+                // Multi-line answers, like drawings, start on their own line
+                let separator = if answer.contains('\n') {"\n"} else {" "};
+                println!("Day {day} part {current_part} ({duration:?}):{separator}{answer}");
+            }
+            Format::Json => {
+                let json = JsonAnswer {
+                    day,
+                    part: current_part,
+                    answer: &answer,
+                    parse_ns: run.parse_time.as_nanos(),
+                    solve_ns: run.solve_time.as_nanos(),
+                };
+                println!("{}", serde_json::to_string(&json).unwrap());
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn fetch(day: u8, force: bool, session: String, cache: Option<&Path>) -> ExitCode {
+    let Some(cache) = cache else {
+        eprintln!("There is no cache directory on this system, give one with --cache-dir");
+        return ExitCode::FAILURE;
+    };
+
+    let website = Website::new(Ureq::default(), website::URL, session);
+    match cache::fetch(&website, cache, day, force) {
+        Ok(Fetched::Downloaded(path)) => println!("Day {day} input saved to {}", path.display()),
+        Ok(Fetched::Cached(path)) =>
+            println!("Day {day} input is already in {}, use --force to download it again", path.display()),
+        Err(error) => {
+            eprintln!("Day {day}: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn submit(day: u8, part: u8, input: Option<String>, session: String, ledger: Option<PathBuf>, cache: Option<&Path>)
+    -> ExitCode
+{
+    let Some(ledger_path) = ledger.or_else(ledger::ledger_path) else {
+        eprintln!("There is no data directory on this system, give a ledger with --ledger");
+        return ExitCode::FAILURE;
+    };
+    let mut ledger = match Ledger::load(&ledger_path) {
+        Ok(ledger) => ledger,
+        Err(error) => {
+            eprintln!("{}: {error}", ledger_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some((path, input)) = read_puzzle(day, input, cache) else {
+        return ExitCode::FAILURE;
+    };
+    let answer = match aoc::solve(day, part, &input) {
+        Ok(Some(answer)) => answer,
+        Ok(None) => {
+            eprintln!("Day {day} part {part} has no solution");
+            return ExitCode::FAILURE;
+        }
+        Err(error) => {
+            eprintln!("{path}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let website = Website::new(Ureq::default(), website::URL, session);
+    let outcome = match ledger.submit(&website, day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            eprintln!("Day {day} part {part}: cannot submit {answer}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = ledger.save(&ledger_path) {
+        eprintln!("{}: {error}", ledger_path.display());
+        return ExitCode::FAILURE;
+    }
+
+    match outcome {
+        Outcome::AlreadyCorrect => {
+            println!("Day {day} part {part}: {answer} is already known to be right");
+            ExitCode::SUCCESS
+        }
+        Outcome::KnownWrong(submission) => {
+            eprintln!("Day {day} part {part}: {answer} is known to be wrong, not submitting it. {}", submission.message);
+            ExitCode::FAILURE
+        }
+        Outcome::Submitted(submission) => {
+            println!("Day {day} part {part}: submitted {answer}. {}", submission.message);
+            if submission.verdict == Verdict::Correct {ExitCode::SUCCESS} else {ExitCode::FAILURE}
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let cache = cli.cache_dir.or_else(cache::cache_dir);
+
+    match cli.command {
+        Command::Run { day, part, input, format } => run(day, part, input, format, cache.as_deref()),
+        Command::Fetch { day, force, session } => fetch(day, force, session, cache.as_deref()),
+        Command::Submit { day, part, input, session, ledger } =>
+            submit(day, part, input, session, ledger, cache.as_deref()),
+    }
+}
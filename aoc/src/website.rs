@@ -0,0 +1,189 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use serde::{Deserialize, Serialize};
+
+/// Address of the Advent of Code website.
+pub const URL: &str = "https://adventofcode.com";
+
+const YEAR: u16 = 2022;
+const USER_AGENT: &str = "aoc-2022 runner";
+
+/// Reason why a request to the website failed.
+#[derive(Debug)]
+pub enum HttpError {
+    /// The server answered with an error status, like 400 for an expired
+    /// session or 404 for a puzzle that is not unlocked yet.
+    Status { code: u16, body: String },
+    /// The server could not be reached or its response could not be read.
+    Transport(String),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HttpError::Status { code, body } => write!(f, "the server answered {code}: {}", body.trim()),
+            HttpError::Transport(error) => write!(f, "cannot reach the server: {error}"),
+        }
+    }
+}
+
+impl Error for HttpError {}
+
+/// The HTTP requests needed to talk to the website, so that tests can swap
+/// the real client for a stand-in.
+pub trait Http {
+    /// Sends a GET request to `url` with the `session` cookie and returns the
+    /// body of the response.
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError>;
+
+    /// Sends `form` in a POST request to `url` with the `session` cookie and
+    /// returns the body of the response.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError>;
+}
+
+/// [`Http`] client doing real requests.
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Default for Ureq {
+    fn default() -> Self {
+        Ureq { agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build() }
+    }
+}
+
+impl Ureq {
+    fn body(response: Result<ureq::Response, ureq::Error>) -> Result<String, HttpError> {
+        match response {
+            Ok(response) => response.into_string()
+                .map_err(|error| HttpError::Transport(error.to_string())),
+            Err(ureq::Error::Status(code, response)) => Err(HttpError::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(error) => Err(HttpError::Transport(error.to_string())),
+        }
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError> {
+        Ureq::body(self.agent.get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call())
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError> {
+        Ureq::body(self.agent.post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form))
+    }
+}
+
+/// What the website thinks of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    /// Wrong, and above the right answer.
+    TooHigh,
+    /// Wrong, and below the right answer.
+    TooLow,
+    /// Wrong, without any hint.
+    Wrong,
+    /// Not checked, because the previous answer was given too recently.
+    TooRecent,
+    /// Not checked, because the part is already solved or not unlocked yet.
+    WrongLevel,
+    /// Not checked, for a reason this runner does not recognize.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer has been checked and found wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// The website's reply to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// The text shown by the website, without its markup.
+    pub message: String,
+}
+
+impl Reply {
+    /// Reads the reply out of the page returned after submitting an answer.
+    pub fn from_page(page: &str) -> Self {
+        let article = page.split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        let message = text(article);
+
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        };
+
+        Reply { verdict, message }
+    }
+}
+
+/// Text of the HTML `html`, without its tags and with its whitespace collapsed.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The Advent of Code 2022 on a website at `base_url`, seen by the user
+/// logged in with `session`.
+pub struct Website<H> {
+    http: H,
+    base_url: String,
+    session: String,
+}
+
+impl<H: Http> Website<H> {
+    pub fn new(http: H, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Website { http, base_url, session: session.into() }
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, HttpError> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        self.http.get(&url, &self.session)
+    }
+
+    /// Submits `answer` as the answer of `part` of `day`.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Reply, HttpError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let page = self.http.post_form(&url, &self.session, &[("level", &level), ("answer", answer)])?;
+        Ok(Reply::from_page(&page))
+    }
+}
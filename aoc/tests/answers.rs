@@ -0,0 +1,88 @@
+//! Checks the answers of every day against the ones recorded in `answers.toml`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use serde::Deserialize;
+
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Deserialize)]
+struct Manifest {
+    example: Vec<Answers>,
+    input: Vec<Answers>,
+}
+
+#[derive(Deserialize)]
+struct Answers {
+    day: u8,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn manifest() -> Manifest {
+    let path = workspace_root().join("answers.toml");
+    let manifest = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Cannot read '{}': {error}", path.display()));
+    toml::from_str(&manifest)
+        .unwrap_or_else(|error| panic!("Invalid '{}': {error}", path.display()))
+}
+
+/// Runs each day of `answers` on its `file` and returns a description of every
+/// answer that does not match. Days whose file is missing are skipped when
+/// `optional` is set.
+fn check(answers: &[Answers], file: &str, optional: bool) -> Vec<String> {
+    let mut failures = vec![];
+
+    for answers in answers {
+        let day = answers.day;
+        let path: PathBuf = workspace_root().join(format!("day{day}")).join(file);
+        if optional && !path.exists() {
+            continue;
+        }
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("Cannot read '{}': {error}", path.display()));
+
+        for (part, expected) in [(1, &answers.part1), (2, &answers.part2)] {
+            let Some(expected) = expected else {continue};
+            match aoc::solve(day, part, &input) {
+                Ok(Some(answer)) if answer == *expected => (),
+                Ok(Some(answer)) => failures.push(
+                    format!("day {day} part {part} on {file}: expected {expected}, got {answer}")),
+                Ok(None) => failures.push(
+                    format!("day {day} part {part} on {file}: expected {expected}, got no solution")),
+                Err(error) => failures.push(
+                    format!("day {day} part {part} on {file}: {error}")),
+            }
+        }
+    }
+
+    failures
+}
+
+/// Runs `check` on a thread with a big stack, like the one of the main thread,
+/// since some days recurse deeper than the default stack of the tests allows.
+fn check_with_big_stack(answers: Vec<Answers>, file: &'static str, optional: bool) {
+    let failures = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || check(&answers, file, optional))
+        .unwrap()
+        .join()
+        .unwrap();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn examples() {
+    check_with_big_stack(manifest().example, "test.txt", false);
+}
+
+#[test]
+#[ignore = "personal inputs are slow, run with --ignored"]
+fn personal_inputs() {
+    check_with_big_stack(manifest().input, "input.txt", true);
+}
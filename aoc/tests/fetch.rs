@@ -0,0 +1,92 @@
+//! Fetches inputs from a local stand-in of the website, without any network.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use aoc::cache::{self, FetchError, Fetched};
+use aoc::website::{HttpError, Ureq, Website};
+use tiny_http::{Response, Server};
+
+const SESSION: &str = "53616c7465645f5f";
+const INPUT: &str = "1000\n2000\n\n3000\n";
+
+/// Starts a server answering the input of day 1 to the right session. Returns
+/// its address and the number of requests it has received.
+fn stand_in() -> (String, Arc<AtomicUsize>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr());
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = Arc::clone(&requests);
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let logged_in = request.headers().iter()
+                .any(|header| header.field.equiv("Cookie") && header.value == format!("session={SESSION}"));
+            let response = match (logged_in, request.url()) {
+                (false, _) => Response::from_string("Puzzle inputs differ by user.  Please log in to get your puzzle input.")
+                    .with_status_code(400),
+                (true, "/2022/day/1/input") => Response::from_string(INPUT),
+                (true, _) => Response::from_string("Not Found").with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+/// Empty cache directory dedicated to the test `name`.
+fn empty_cache(name: &str) -> PathBuf {
+    let cache = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&cache);
+    cache
+}
+
+#[test]
+fn downloads_into_the_cache() {
+    let (url, requests) = stand_in();
+    let cache = empty_cache("download");
+    let website = Website::new(Ureq::default(), url, SESSION);
+
+    let fetched = cache::fetch(&website, &cache, 1, false).unwrap();
+
+    assert_eq!(fetched, Fetched::Downloaded(cache::cached_input(&cache, 1)));
+    assert_eq!(fs::read_to_string(cache::cached_input(&cache, 1)).unwrap(), INPUT);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn keeps_cached_inputs_unless_forced() {
+    let (url, requests) = stand_in();
+    let cache = empty_cache("force");
+    let path = cache::cached_input(&cache, 1);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "edited by hand").unwrap();
+    let website = Website::new(Ureq::default(), url, SESSION);
+
+    assert_eq!(cache::fetch(&website, &cache, 1, false).unwrap(), Fetched::Cached(path.clone()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "edited by hand");
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+    assert_eq!(cache::fetch(&website, &cache, 1, true).unwrap(), Fetched::Downloaded(path.clone()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+}
+
+#[test]
+fn reports_refused_requests_without_caching() {
+    let (url, _) = stand_in();
+    let cache = empty_cache("refused");
+
+    let logged_out = Website::new(Ureq::default(), &url, "expired");
+    let error = cache::fetch(&logged_out, &cache, 1, false).unwrap_err();
+    assert!(matches!(error, FetchError::Http(HttpError::Status { code: 400, .. })), "{error}");
+
+    let locked = Website::new(Ureq::default(), &url, SESSION);
+    let error = cache::fetch(&locked, &cache, 2, false).unwrap_err();
+    assert!(matches!(error, FetchError::Http(HttpError::Status { code: 404, .. })), "{error}");
+
+    assert!(!cache.exists());
+}
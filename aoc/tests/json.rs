@@ -0,0 +1,27 @@
+//! Checks the output of `aoc run --format json`.
+
+use std::path::Path;
+use std::process::Command;
+use serde_json::Value;
+
+#[test]
+fn prints_one_object_per_part() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(root)
+        .args(["run", "--day", "1", "--input", "day1/test.txt", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let lines: Vec<Value> = String::from_utf8(output.stdout).unwrap().lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    for (line, (part, answer)) in lines.iter().zip([(1, "24000"), (2, "45000")]) {
+        assert_eq!(line["day"], 1);
+        assert_eq!(line["part"], part);
+        assert_eq!(line["answer"], answer);
+        assert!(line["parse_ns"].is_u64() && line["solve_ns"].is_u64(), "{line}");
+    }
+}
@@ -0,0 +1,122 @@
+//! Submits answers to a local stand-in of the website, without any network.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use aoc::ledger::{Ledger, Outcome};
+use aoc::website::{Ureq, Verdict, Website};
+use tiny_http::{Response, Server};
+
+const SESSION: &str = "53616c7465645f5f";
+const RIGHT_ANSWER: u64 = 24000;
+/// Answer the stand-in refuses to check, as if it came too soon after another.
+const TOO_SOON: &str = "0";
+
+/// Starts a server checking the answers of day 1 part 1 like the website does.
+/// Returns its address and the number of answers it has received.
+fn stand_in() -> (String, Arc<AtomicUsize>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr());
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = Arc::clone(&requests);
+    thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let mut form = String::new();
+            request.as_reader().read_to_string(&mut form).unwrap();
+            assert_eq!(request.url(), "/2022/day/1/answer");
+            let answer = form.split('&')
+                .find_map(|field| field.strip_prefix("answer="))
+                .unwrap();
+
+            let message = match answer.parse::<u64>() {
+                _ if answer == TOO_SOON => "You gave an answer too recently; you have to wait after submitting an \
+                    answer before trying again.  You have 37s left to wait.",
+                Ok(RIGHT_ANSWER) => "That's the right answer!  You are one gold star closer to collecting enough \
+                    star fruit.",
+                Ok(answer) if answer > RIGHT_ANSWER => "That's not the right answer; your answer is too high.  \
+                    If you're stuck, make sure you're using the full input data.",
+                Ok(_) => "That's not the right answer; your answer is too low.",
+                Err(_) => "That's not the right answer.  If you're stuck, make sure you're using the full input \
+                    data.",
+            };
+            let page = format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>");
+            request.respond(Response::from_string(page)).unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+fn verdict(outcome: Outcome) -> Verdict {
+    match outcome {
+        Outcome::Submitted(submission) => submission.verdict,
+        outcome => panic!("the answer was not submitted: {outcome:?}"),
+    }
+}
+
+#[test]
+fn never_resubmits_once_solved() {
+    let (url, requests) = stand_in();
+    let website = Website::new(Ureq::default(), url, SESSION);
+    let mut ledger = Ledger::default();
+
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, "24000").unwrap()), Verdict::Correct);
+    assert_eq!(ledger.correct(1, 1), Some("24000"));
+
+    assert_eq!(ledger.submit(&website, 1, 1, "24000").unwrap(), Outcome::AlreadyCorrect);
+    assert!(matches!(ledger.submit(&website, 1, 1, "12").unwrap(), Outcome::KnownWrong(_)));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn refuses_answers_known_to_be_wrong() {
+    let (url, requests) = stand_in();
+    let website = Website::new(Ureq::default(), url, SESSION);
+    let mut ledger = Ledger::default();
+
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, "30000").unwrap()), Verdict::TooHigh);
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, "20000").unwrap()), Verdict::TooLow);
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, "elves").unwrap()), Verdict::Wrong);
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    for known_wrong in ["30000", "31000", "20000", "100", "elves"] {
+        let outcome = ledger.submit(&website, 1, 1, known_wrong).unwrap();
+        assert!(matches!(outcome, Outcome::KnownWrong(_)), "{known_wrong}: {outcome:?}");
+    }
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, "24000").unwrap()), Verdict::Correct);
+    assert_eq!(ledger.submissions().len(), 4);
+}
+
+#[test]
+fn retries_answers_that_were_not_checked() {
+    let (url, requests) = stand_in();
+    let website = Website::new(Ureq::default(), url, SESSION);
+    let mut ledger = Ledger::default();
+
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, TOO_SOON).unwrap()), Verdict::TooRecent);
+    assert_eq!(verdict(ledger.submit(&website, 1, 1, TOO_SOON).unwrap()), Verdict::TooRecent);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn keeps_the_ledger_on_disk() {
+    let (url, _) = stand_in();
+    let website = Website::new(Ureq::default(), url, SESSION);
+    let path = std::env::temp_dir().join(format!("aoc-ledger-{}.toml", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut ledger = Ledger::load(&path).unwrap();
+    assert!(ledger.submissions().is_empty());
+    ledger.submit(&website, 1, 1, "30000").unwrap();
+    ledger.save(&path).unwrap();
+
+    let ledger = Ledger::load(&path).unwrap();
+    assert_eq!(ledger.submissions().len(), 1);
+    assert_eq!(ledger.submissions()[0].verdict, Verdict::TooHigh);
+    assert!(ledger.submissions()[0].message.starts_with("That's not the right answer; your answer is too high."));
+    assert!(ledger.known_wrong(1, 1, "30000").is_some());
+}
@@ -0,0 +1,20 @@
+use aoc_core::{input_path, read_input, ParseError, Solution};
+use day1::Day1;
+
+const HISTOGRAM_BINS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 50;
+
+fn main() -> Result<(), ParseError> {
+    let input = read_input(input_path());
+    let elves = Day1::parse(&input)?;
+
+    let Some(stats) = elves.stats() else {
+        println!("There is no elf");
+        return Ok(());
+    };
+
+    println!("Total calories carried by the elves:\n\n{stats}\n");
+    print!("{}", stats.histogram(HISTOGRAM_BINS, HISTOGRAM_WIDTH));
+
+    Ok(())
+}
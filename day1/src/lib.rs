@@ -0,0 +1,166 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use aoc_core::{lines, ParseError, Solution};
+
+mod stats;
+
+pub use stats::Stats;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Elves;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(elves: &Self::Input) -> u32 {
+        elves.top_k_sum(1)
+    }
+
+    fn part2(elves: &Self::Input) -> u32 {
+        elves.top_k_sum(3)
+    }
+}
+
+/// The calories of the items carried by each elf, in the order of the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elves {
+    groups: Vec<Vec<u32>>,
+}
+
+impl Elves {
+    pub fn new(groups: Vec<Vec<u32>>) -> Self {
+        Elves { groups }
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Calories of the items carried by the elf at `index`, from 0.
+    pub fn items(&self, index: usize) -> Option<&[u32]> {
+        self.groups.get(index).map(Vec::as_slice)
+    }
+
+    /// Calories of the items carried by each elf.
+    pub fn iter(&self) -> impl Iterator<Item = &[u32]> + '_ {
+        self.groups.iter().map(Vec::as_slice)
+    }
+
+    /// Total calories carried by each elf.
+    pub fn totals(&self) -> impl Iterator<Item = u32> + '_ {
+        self.iter().map(|items| items.iter().sum())
+    }
+
+    /// The `k` elves carrying the most calories, from the most to the least.
+    pub fn top_k(&self, k: usize) -> Vec<TopElf> {
+        top_k_calories(self.totals(), k)
+    }
+
+    /// Sum of the calories carried by the `k` elves carrying the most.
+    pub fn top_k_sum(&self, k: usize) -> u32 {
+        self.top_k(k).iter().map(|elf| elf.calories).sum()
+    }
+
+    /// Statistics on the total calories carried by the elves, or `None` when
+    /// there is no elf.
+    pub fn stats(&self) -> Option<Stats> {
+        Stats::new(self.totals().collect())
+    }
+}
+
+/// Returns the calories of the items carried by each elf. Elves are separated
+/// by an empty line, which may also end the input. An empty input has no elf.
+pub fn parse_input(input: &str) -> Result<Elves, ParseError> {
+    let mut groups = vec![vec![]];
+    for line in lines(input) {
+        if line.text.is_empty() {
+            groups.push(vec![]);
+        }
+        else {
+            groups.last_mut().unwrap().push(line.parse(line.text)?);
+        }
+    }
+    if groups.last().is_some_and(Vec::is_empty) {
+        groups.pop();
+    }
+
+    Ok(Elves::new(groups))
+}
+
+/// An elf among the ones carrying the most calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TopElf {
+    pub calories: u32,
+    /// Position of the elf in the input, from 0.
+    pub index: usize,
+}
+
+/// Returns the `k` elves carrying the most calories, from the most to the least,
+/// given the total of every elf. Only `k` elves are kept at any time, so `totals`
+/// can be streamed. Between elves carrying as much, the first ones win.
+pub fn top_k_calories(totals: impl IntoIterator<Item = u32>, k: usize) -> Vec<TopElf> {
+    // Min-heap of the best elves so far, the worst of them on top
+    let mut top = BinaryHeap::with_capacity(k + 1);
+    for (index, calories) in totals.into_iter().enumerate() {
+        // Later elves rank lower than earlier ones carrying as much
+        top.push(Reverse((calories, Reverse(index))));
+        if top.len() > k {
+            top.pop();
+        }
+    }
+
+    top.into_sorted_vec().into_iter()
+        .map(|Reverse((calories, Reverse(index)))| TopElf { calories, index })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_k_keeps_the_index_of_each_elf() {
+        let top = top_k_calories([10, 40, 20, 30], 2);
+        assert_eq!(top, [TopElf { calories: 40, index: 1 }, TopElf { calories: 30, index: 3 }]);
+    }
+
+    #[test]
+    fn top_k_prefers_the_first_elves_on_ties() {
+        let top = top_k_calories([5, 7, 7, 5, 7], 3);
+        assert_eq!(top.iter().map(|elf| elf.index).collect::<Vec<_>>(), [1, 2, 4]);
+        let top = top_k_calories([5, 7, 5], 2);
+        assert_eq!(top.iter().map(|elf| elf.index).collect::<Vec<_>>(), [1, 0]);
+    }
+
+    #[test]
+    fn parses_elves_with_or_without_a_final_empty_line() {
+        let elves = parse_input("1\n2\n\n3\n").unwrap();
+        assert_eq!(elves.iter().collect::<Vec<_>>(), [&[1, 2][..], &[3]]);
+        assert_eq!(parse_input("1\n2\n\n3\n\n").unwrap(), elves);
+        assert_eq!(elves.totals().collect::<Vec<_>>(), [3, 3]);
+    }
+
+    #[test]
+    fn an_empty_input_has_no_elf() {
+        let elves = parse_input("").unwrap();
+        assert!(elves.is_empty());
+        assert_eq!(elves.stats(), None);
+        assert_eq!(elves.top_k_sum(3), 0);
+    }
+
+    #[test]
+    fn top_k_with_fewer_elves_or_none_wanted() {
+        assert_eq!(top_k_calories([3, 9], 5).len(), 2);
+        assert!(top_k_calories([3, 9], 0).is_empty());
+        assert!(top_k_calories([], 3).is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::{self, Display, Write};
+
+/// Statistics on the total calories carried by the elves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// Totals of every elf, from the least to the most. Never empty.
+    sorted: Vec<u32>,
+}
+
+impl Stats {
+    /// Computes the statistics of `totals`, or returns `None` if there is none.
+    pub fn new(mut totals: Vec<u32>) -> Option<Self> {
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort_unstable();
+        Some(Stats { sorted: totals })
+    }
+
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    pub fn min(&self) -> u32 {
+        self.sorted[0]
+    }
+
+    pub fn max(&self) -> u32 {
+        self.sorted[self.count() - 1]
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sorted.iter().map(|&total| total as f64).sum::<f64>() / self.count() as f64
+    }
+
+    /// Middle total, or the mean of the two middle ones when there is an even
+    /// number of elves.
+    pub fn median(&self) -> f64 {
+        let middle = self.count() / 2;
+        if self.count().is_multiple_of(2) {
+            (self.sorted[middle - 1] as f64 + self.sorted[middle] as f64) / 2.
+        } else {
+            self.sorted[middle] as f64
+        }
+    }
+
+    /// Smallest total that at least `percent`% of the elves do not exceed,
+    /// following the nearest-rank method.
+    pub fn percentile(&self, percent: f64) -> u32 {
+        let rank = (percent.clamp(0., 100.) / 100. * self.count() as f64).ceil() as usize;
+        self.sorted[rank.max(1) - 1]
+    }
+
+    pub fn p90(&self) -> u32 {
+        self.percentile(90.)
+    }
+
+    pub fn p99(&self) -> u32 {
+        self.percentile(99.)
+    }
+
+    /// Draws how many elves carry each range of totals, splitting the totals
+    /// into `bins` ranges of the same size give or take one, or fewer when
+    /// there are fewer different totals in the range. The longest bar is
+    /// `width` wide.
+    pub fn histogram(&self, bins: usize, width: usize) -> String {
+        // Number of different totals from the least to the most
+        let values = u64::from(self.max() - self.min()) + 1;
+        let bins = bins.clamp(1, values as usize);
+        // Offset from the least total where `bin` starts, the last bin ending
+        // right after the most
+        let start = |bin: usize| (bin as u64 * values).div_ceil(bins as u64);
+        let mut counts = vec![0; bins];
+        for &total in &self.sorted {
+            counts[(u64::from(total - self.min()) * bins as u64 / values) as usize] += 1;
+        }
+
+        let max_count = counts.iter().copied().max().unwrap();
+        let digits = self.max().to_string().len();
+        let mut histogram = String::new();
+        for (bin, count) in counts.into_iter().enumerate() {
+            let low = u64::from(self.min()) + start(bin);
+            let high = u64::from(self.min()) + start(bin + 1) - 1;
+            // Any elf at all gets at least one mark
+            let bar = "#".repeat((count * width).div_ceil(max_count));
+            writeln!(histogram, "{low:>digits$} - {high:>digits$} | {bar} {count}").unwrap();
+        }
+
+        histogram
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "count  {}", self.count())?;
+        writeln!(f, "min    {}", self.min())?;
+        writeln!(f, "max    {}", self.max())?;
+        writeln!(f, "mean   {:.1}", self.mean())?;
+        writeln!(f, "median {:.1}", self.median())?;
+        writeln!(f, "p90    {}", self.p90())?;
+        write!(f, "p99    {}", self.p99())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_stats_without_totals() {
+        assert_eq!(Stats::new(vec![]), None);
+    }
+
+    #[test]
+    fn summary() {
+        let stats = Stats::new(vec![40, 10, 30, 20]).unwrap();
+        assert_eq!((stats.count(), stats.min(), stats.max()), (4, 10, 40));
+        assert_eq!(stats.mean(), 25.);
+        assert_eq!(stats.median(), 25.);
+        assert_eq!(Stats::new(vec![3, 1, 2]).unwrap().median(), 2.);
+    }
+
+    #[test]
+    fn percentiles_follow_the_nearest_rank() {
+        let stats = Stats::new((1..=10).rev().collect()).unwrap();
+        assert_eq!(stats.percentile(0.), 1);
+        assert_eq!(stats.percentile(10.), 1);
+        assert_eq!(stats.percentile(11.), 2);
+        assert_eq!(stats.percentile(50.), 5);
+        assert_eq!(stats.p90(), 9);
+        assert_eq!(stats.p99(), 10);
+        assert_eq!(stats.percentile(100.), 10);
+        assert_eq!(stats.percentile(250.), 10);
+    }
+
+    #[test]
+    fn histogram_splits_the_totals_into_bins() {
+        let stats = Stats::new(vec![0, 1, 2, 3, 3, 3, 9]).unwrap();
+        assert_eq!(stats.histogram(2, 4), "0 - 4 | #### 6\n5 - 9 | # 1\n");
+        assert_eq!(Stats::new(vec![7]).unwrap().histogram(3, 2), "7 - 7 | ## 1\n");
+    }
+
+    #[test]
+    fn the_last_bin_ends_at_the_largest_total() {
+        // 12 different totals do not split evenly into 5 bins
+        let stats = Stats::new((0..=11).collect()).unwrap();
+        assert_eq!(stats.histogram(5, 3).lines().collect::<Vec<_>>(), [
+            " 0 -  2 | ### 3",
+            " 3 -  4 | ## 2",
+            " 5 -  7 | ### 3",
+            " 8 -  9 | ## 2",
+            "10 - 11 | ## 2",
+        ]);
+        let stats = Stats::new(vec![0, u32::MAX]).unwrap();
+        assert!(stats.histogram(3, 1).ends_with(&format!("- {} | # 1\n", u32::MAX)));
+    }
+}
@@ -0,0 +1,137 @@
+use aoc_core::{lines, ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> i32 {
+        get_signal_sum(instructions)
+    }
+
+    fn part2(instructions: &Self::Input) -> String {
+        draw_sprite(instructions).join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut parsed_line;
+    let mut instructions = Vec::new();
+
+    for line in lines(input) {
+        parsed_line = line.text.split_whitespace();
+        instructions.push(match line.field(parsed_line.next(), "instruction")? {
+            "noop" => Instruction::Noop,
+            "addx" => Instruction::Addx(line.parse_field(parsed_line.next(), "addx value")?),
+            instruction => return Err(line.unexpected(instruction)),
+        });
+    }
+
+    Ok(instructions)
+}
+
+fn check_cycle_goal(cycle_goal:i32, current_cycle:i32, cpu_register:i32) -> Option<i32> {
+    if cycle_goal == current_cycle {
+        return Some(cycle_goal*cpu_register);
+    }
+    None
+}
+
+pub fn get_signal_sum(instructions: &[Instruction]) -> i32 {
+    let cycles = [20,60,100,140,180,220];
+    let mut current_cycle_goal: usize = 0;
+    let mut current_cycle = 0;
+    let mut sum_signal_strength = 0;
+    let mut cpu_register = 1;
+
+    'outer: for instruction in instructions {
+        match instruction {
+            Instruction::Noop => {
+                current_cycle += 1;
+                if let Some(signal_strength) = check_cycle_goal(
+                    cycles[current_cycle_goal], current_cycle, cpu_register) {
+                    sum_signal_strength += signal_strength;
+                    current_cycle_goal += 1;
+                    if current_cycle_goal == cycles.len() {break 'outer}
+                }
+            }
+            Instruction::Addx(value) => {
+                for _ in 0..2 {
+                    current_cycle += 1;
+                    if let Some(signal_strength) = check_cycle_goal(
+                        cycles[current_cycle_goal], current_cycle, cpu_register) {
+                        sum_signal_strength += signal_strength;
+                        current_cycle_goal += 1;
+                        if current_cycle_goal == cycles.len() {break 'outer}
+                    }
+                }
+                cpu_register += value;
+            }
+        }
+        
+    }
+    
+    sum_signal_strength
+}
+
+
+fn check_cycle_goal_v2(cycle_goal:i32, current_cycle:i32, cpu_register:i32, pixels: &mut String) {
+    let current_market_position = current_cycle - cycle_goal + 40;
+    if cpu_register <= current_market_position && cpu_register+2 >= current_market_position {
+        pixels.push('#');
+    }
+    else {
+        pixels.push('.'); 
+    }
+}
+
+pub fn draw_sprite(instructions: &[Instruction]) -> Vec<String> {
+    let mut pixel_matrix = vec![String::new(); 6];
+    let cycles = [40,80,120,160,200,240];
+    let mut current_cycle_goal: usize = 0;
+    let mut current_cycle = 0;
+    let mut cpu_register = 1;
+
+    'outer: for instruction in instructions {
+        match instruction {
+            Instruction::Noop => {
+                current_cycle += 1;
+                check_cycle_goal_v2(
+                    cycles[current_cycle_goal], 
+                    current_cycle, 
+                    cpu_register,
+                    &mut pixel_matrix[current_cycle_goal]);
+                    if cycles[current_cycle_goal] == current_cycle {current_cycle_goal += 1;}
+                    if current_cycle_goal == cycles.len() {break 'outer}
+            }
+            Instruction::Addx(value) => {
+                for _ in 0..2 {
+                    current_cycle += 1;
+                    check_cycle_goal_v2(
+                        cycles[current_cycle_goal], 
+                        current_cycle, 
+                        cpu_register,
+                        &mut pixel_matrix[current_cycle_goal]);
+                        if cycles[current_cycle_goal] == current_cycle {current_cycle_goal += 1;}
+                        if current_cycle_goal == cycles.len() {break 'outer}
+                }
+                cpu_register += value;
+            }
+        }
+        
+    }
+    
+    pixel_matrix
+}
@@ -0,0 +1,277 @@
+use core::panic;
+use aoc_core::{lines, Line, ParseError, Solution};
+use regex::Regex;
+use std::collections::VecDeque;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> usize {
+        calculate_monkey_business(monkeys.clone())
+    }
+
+    fn part2(monkeys: &Self::Input) -> usize {
+        calculate_monkey_business_v2(monkeys.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u128>,
+    operations:Vec<String>,
+    divisible_by: u128,
+    throw_to_if_true: u128,
+    throw_to_if_false: u128,
+}
+
+fn monkey_operation(operations:&[String], old:u128) -> u128 {
+    let var1 = match &operations[0][..] {
+        "old" => old,
+        number => number.parse::<u128>().unwrap(),
+    };
+
+    let var2 = match &operations[2][..] {
+        "old" => old,
+        number => number.parse::<u128>().unwrap(),
+    };
+
+    match &operations[1][..] {
+        "+" => var1 + var2,
+        "*" => var1 * var2,
+        _ => panic!("Should be either '*' or '+'")
+    }
+}
+
+fn next_line<'a>(lines: &mut impl Iterator<Item = Line<'a>>, previous: &Line, field: &'static str) -> Result<Line<'a>, ParseError> {
+    lines.next().ok_or(ParseError::MissingField { line: previous.number + 1, column: 1, field })
+}
+
+fn find_number(line: &Line, number_re: &Regex, field: &'static str) -> Result<u128, ParseError> {
+    let digits = number_re.find(line.text).ok_or_else(|| line.missing(field))?;
+    line.parse(digits.as_str())
+}
+
+/// Reads the monkey after `header`, and the lines of its two throw targets
+/// into `targets` to check them once every monkey is known.
+fn fill_next_monkey_input<'a>(
+    monkeys: &mut Vec<Monkey>,
+    targets: &mut Vec<(usize, u128)>,
+    header: &Line,
+    lines: &mut impl Iterator<Item = Line<'a>>,
+) -> Result<(), ParseError> {
+    // Get equiped items
+    let number_re = Regex::new(r"\d+").unwrap();
+    let line = next_line(lines, header, "starting items")?;
+    let mut items = VecDeque::new();
+    for digits in number_re.find_iter(line.text) {
+        items.push_back(line.parse(digits.as_str())?);
+    }
+    
+    // Create inspection operation
+    let line = next_line(lines, &line, "operation")?;
+    let (_, operation) = line.text.split_once('=').ok_or_else(|| line.missing("operation"))?;
+    let operations: Vec<&str> = operation.split_whitespace().collect();
+    if operations.len() < 3 {return Err(line.missing("operand"));}
+    for operand in [operations[0], operations[2]] {
+        if operand != "old" {line.parse::<u128>(operand)?;}
+    }
+    if !["+", "*"].contains(&operations[1]) {return Err(line.unexpected(operations[1]));}
+    if let Some(extra) = operations.get(3) {return Err(line.unexpected(extra));}
+    let operations = operations.into_iter().map(|s| s.to_string()).collect::<Vec<String>>();
+
+    // Get test from monkey
+    let line = next_line(lines, &line, "test")?;
+    let divisible_by = find_number(&line, &number_re, "divisor")?;
+    if divisible_by == 0 {
+        return Err(ParseError::Invalid { line: line.number, reason: "cannot test divisibility by 0".to_string() });
+    }
+
+    // Get monkey int to throw if test is true
+    let line = next_line(lines, &line, "monkey to throw to if true")?;
+    let throw_to_if_true = find_number(&line, &number_re, "monkey")?;
+    targets.push((line.number, throw_to_if_true));
+
+    // Get monkey int to throw if test is false
+    let line = next_line(lines, &line, "monkey to throw to if false")?;
+    let throw_to_if_false = find_number(&line, &number_re, "monkey")?;
+    targets.push((line.number, throw_to_if_false));
+
+    // Discard empty line
+    lines.next();
+
+    // Push money to vector
+    monkeys.push(
+        Monkey {
+            items,
+            operations,
+            divisible_by,
+            throw_to_if_true,
+            throw_to_if_false,
+        }
+    );
+
+    Ok(())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut lines = lines(input);
+
+    let mut monkeys = vec![];
+    let mut targets = vec![];
+
+    while let Some(header) = lines.next() {
+        fill_next_monkey_input(&mut monkeys, &mut targets, &header, &mut lines)?;
+    }
+
+    if monkeys.is_empty() {
+        return Err(ParseError::Invalid { line: 1, reason: "no monkey found".to_string() });
+    }
+    for (line, target) in targets {
+        if target >= monkeys.len() as u128 {
+            let reason = format!("cannot throw to monkey {target}, there are {} monkeys", monkeys.len());
+            return Err(ParseError::Invalid { line, reason });
+        }
+    }
+
+    Ok(monkeys)
+}
+
+
+#[allow(clippy::needless_range_loop)]
+pub fn calculate_monkey_business(mut monkeys: Vec<Monkey>) -> usize  {
+
+    let mut inspected_items = vec![0; monkeys.len()];
+    let mut thrown_items = vec![];
+
+    for _ in 0..20 {
+        for n_monk in 0..monkeys.len() {
+            let monkey: &mut Monkey = &mut (&mut monkeys)[n_monk];
+            inspected_items[n_monk] += monkey.items.len();
+
+            // Calculate the thrown items
+            while let Some(item) = monkey.items.pop_front() {
+                let op_result = monkey_operation(&monkey.operations,item) / 3;
+
+                if op_result.is_multiple_of(monkey.divisible_by) {
+                    thrown_items.push((monkey.throw_to_if_true, op_result));
+                }
+                else {
+                    thrown_items.push((monkey.throw_to_if_false, op_result));
+                }
+            }
+            // Throw the items
+            for (to, worry_level) in &thrown_items {
+                monkeys[*to as usize].items.push_back(*worry_level);
+            }
+            thrown_items.clear();
+        }
+    }
+
+    let (mut max1, mut max2) = (0, 0);
+    // Iterate through the vector and find the two maximum values
+    for number in inspected_items {
+        if number > max1 {
+            max2 = max1;
+            max1 = number;
+        } else if number > max2 {
+            max2 = number;
+        }
+    }
+
+    max1*max2
+}
+
+
+#[allow(clippy::needless_range_loop)]
+pub fn calculate_monkey_business_v2(mut monkeys: Vec<Monkey>) -> usize  {
+    let module = monkeys.iter().map(|x| x.divisible_by).reduce(|x, y| x*y).unwrap();
+
+    let mut inspected_items = vec![0; monkeys.len()];
+    let mut thrown_items = vec![];
+
+    for _ in 0..10000 {
+        for n_monk in 0..monkeys.len() {
+            let monkey: &mut Monkey = &mut (&mut monkeys)[n_monk];
+            inspected_items[n_monk] += monkey.items.len();
+
+            // Calculate the thrown items
+            while let Some(item) = monkey.items.pop_front() {
+                let op_result = monkey_operation(&monkey.operations,item) % module;
+
+                if op_result.is_multiple_of(monkey.divisible_by) {
+                    thrown_items.push((monkey.throw_to_if_true, op_result));
+                }
+                else {
+                    thrown_items.push((monkey.throw_to_if_false, op_result));
+                }
+            }
+            // Throw the items
+            for (to, worry_level) in &thrown_items {
+                monkeys[*to as usize].items.push_back(*worry_level);
+            }
+            thrown_items.clear();
+        }
+    }
+
+    let (mut max1, mut max2) = (0, 0);
+    // Iterate through the vector and find the two maximum values
+    for number in inspected_items {
+        if number > max1 {
+            max2 = max1;
+            max1 = number;
+        } else if number > max2 {
+            max2 = number;
+        }
+    }
+
+    max1*max2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONKEY: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+    fn invalid_line(input: &str) -> Option<usize> {
+        match parse_input(input) {
+            Err(ParseError::Invalid { line, .. }) => Some(line),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn accepts_targets_in_range() {
+        assert_eq!(parse_input(MONKEY).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rejects_targets_out_of_range() {
+        assert_eq!(invalid_line(&MONKEY.replace("true: throw to monkey 0", "true: throw to monkey 1")), Some(5));
+        assert_eq!(invalid_line(&MONKEY.replace("false: throw to monkey 0", "false: throw to monkey 7")), Some(6));
+    }
+
+    #[test]
+    fn rejects_a_divisor_of_0() {
+        assert_eq!(invalid_line(&MONKEY.replace("by 23", "by 0")), Some(4));
+    }
+
+    #[test]
+    fn rejects_no_monkey() {
+        assert_eq!(invalid_line(""), Some(1));
+    }
+}
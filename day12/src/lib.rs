@@ -0,0 +1,93 @@
+use aoc_core::{Grid, ParseError, Pos, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = ParsedInput;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(parsed_input: &Self::Input) -> u32 {
+        shortest_path_from_start(parsed_input)
+    }
+
+    fn part2(parsed_input: &Self::Input) -> u32 {
+        shortest_path_from_lowest(parsed_input)
+    }
+}
+
+pub type ParsedInput = (Grid<char>, Pos, Pos, Vec<Pos>);
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let mut grid = Grid::parse(input, |ch| (ch.is_ascii_lowercase() || ch == 'S' || ch == 'E').then_some(ch))?;
+
+    let missing = |field| ParseError::MissingField { line: grid.height() + 1, column: 1, field };
+    let find = |marker| grid.enumerate().find(|&(_, &ch)| ch == marker).map(|(pos, _)| pos);
+    let start_position = find('S').ok_or_else(|| missing("start position 'S'"))?;
+    let end_position = find('E').ok_or_else(|| missing("end position 'E'"))?;
+    grid[start_position] = 'a';
+    grid[end_position] = 'z';
+
+    let minimum_height_cells = grid.enumerate()
+        .filter(|&(_, &ch)| ch == 'a')
+        .map(|(pos, _)| pos)
+        .collect();
+
+    Ok((grid, start_position, end_position, minimum_height_cells))
+}
+
+fn visit_cell(
+    char_grid: &Grid<char>,
+    distance_grid: &mut Grid<u32>,
+    step:u32,
+    curr_position: Pos) {
+
+    if step < distance_grid[curr_position] {
+        distance_grid[curr_position] = step;
+        for new_position in char_grid.neighbours(curr_position) {
+            if char::from_u32(char_grid[curr_position] as u32 - 1).unwrap() <= char_grid[new_position] {
+                visit_cell(
+                    char_grid,
+                    distance_grid,
+                    step+1,
+                    new_position,
+                )
+            }
+        }
+    }
+}
+
+fn distances_to_end(char_grid: &Grid<char>, end_position: Pos) -> Grid<u32> {
+    let mut distance_grid = Grid::new(char_grid.width(), char_grid.height(), u32::MAX);
+
+    visit_cell(
+        char_grid,
+        &mut distance_grid,
+        0,
+        end_position);
+
+    distance_grid
+}
+
+pub fn shortest_path_from_start(parsed_input: &ParsedInput) -> u32 {
+    let (char_grid, start_position, end_position, _) = parsed_input;
+
+    let distance_grid = distances_to_end(char_grid, *end_position);
+
+    distance_grid[*start_position]
+}
+
+pub fn shortest_path_from_lowest(parsed_input: &ParsedInput) -> u32 {
+    let (char_grid, _, end_position, minimum_height_cells) = parsed_input;
+
+    let distance_grid = distances_to_end(char_grid, *end_position);
+
+    minimum_height_cells.iter()
+        .map(|&pos| distance_grid[pos])
+        .min()
+        .unwrap_or(u32::MAX)
+}
@@ -0,0 +1,170 @@
+use aoc_core::{lines, Line, ParseError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pair>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> u32 {
+        indices_sum(pairs)
+    }
+
+    fn part2(pairs: &Self::Input) -> u32 {
+        decoder_key(pairs)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ListItem {
+    Number(u32),
+    List(Vec<ListItem>)
+}
+
+/// Left and right packets of a pair.
+pub type Pair = (Vec<ListItem>, Vec<ListItem>);
+
+fn parse_list(line: &Line) -> Result<Vec<ListItem>, ParseError> {
+    // The outermost list is closed after the loop
+    let str_list = line.text.strip_suffix(']').ok_or_else(|| line.missing("closing ']'"))?;
+    let mut vecs: Vec<Vec<ListItem>> = vec![];
+    let mut number_start = None;
+
+    for (idx, ch) in str_list.char_indices() {
+        if ch.is_ascii_digit() {
+            number_start.get_or_insert(idx);
+            continue;
+        }
+        if let Some(start) = number_start.take() {
+            push_number(line, &mut vecs, &str_list[start..idx])?;
+        }
+        let token = &str_list[idx..idx + ch.len_utf8()];
+        match ch {
+            '[' => vecs.push(vec![]),
+            ']' => {
+                let vec = vecs.pop().ok_or_else(|| line.unexpected(token))?;
+                vecs.last_mut().ok_or_else(|| line.unexpected(token))?.push(ListItem::List(vec));
+            }
+            ' ' | ',' => {}
+            _ => return Err(line.unexpected(token)),
+        }
+    }
+
+    if let Some(start) = number_start {
+        push_number(line, &mut vecs, &str_list[start..])?;
+    }
+
+    let list = vecs.pop().ok_or_else(|| line.missing("opening '['"))?;
+    if !vecs.is_empty() {return Err(line.missing("closing ']'"));}
+    Ok(list)
+}
+
+fn push_number(line: &Line, vecs: &mut [Vec<ListItem>], number: &str) -> Result<(), ParseError> {
+    let list = vecs.last_mut().ok_or_else(|| line.unexpected(number))?;
+    list.push(ListItem::Number(line.parse(number)?));
+    Ok(())
+}
+
+fn is_list_ordered(left: &[ListItem], right: &[ListItem]) -> Option<bool> {
+
+    for (i,j) in left.iter().zip(right) {
+        if let (ListItem::Number(i), ListItem::Number(j)) = (i,j) {
+            if i > j {return Some(false);}
+            else if i < j {return Some(true);}
+        }
+        else if let (ListItem::Number(i), ListItem::List(j)) = (i,j) {
+            if let Some(x) = is_list_ordered(&[ListItem::Number(*i)], j) {
+                return Some(x);
+            }
+        }
+        else if let (ListItem::List(i), ListItem::Number(j)) = (i,j) {
+            if let Some(x) = is_list_ordered(i, &[ListItem::Number(*j)]) {
+                return Some(x);
+            }
+        }
+        else if let (ListItem::List(i), ListItem::List(j)) = (i,j) {
+            if let Some(x) =  is_list_ordered(i, j) {
+                return Some(x);
+            }
+        }
+    }
+
+    if left.len() > right.len() {return Some(false);}
+    else if left.len() < right.len() {return Some(true)}
+
+    None
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let mut lines = lines(input);
+
+    let mut pairs = vec![];
+    while let Some(line1) = lines.next() {
+        let line2 = lines.next()
+            .ok_or(ParseError::MissingField { line: line1.number + 1, column: 1, field: "second packet" })?;
+        pairs.push((parse_list(&line1)?, parse_list(&line2)?));
+        // Remove the empty line
+        if let Some(line) = lines.next().filter(|line| !line.text.is_empty()) {
+            return Err(line.unexpected_at(1, line.text));
+        }
+    }
+
+    Ok(pairs)
+}
+
+pub fn indices_sum(pairs: &[Pair]) -> u32 {
+    let mut idx_sum = 0;
+    for (it, (left, right)) in (1..).zip(pairs) {
+        if is_list_ordered(left, right).unwrap_or(true) {
+            idx_sum += it;
+        }
+    }
+    
+    idx_sum
+}
+
+fn parse_to_number_list(list_items: &[ListItem]) -> Vec<i32> {
+
+    let mut vec = vec![];
+
+    if list_items.is_empty() {
+        return vec![-1];
+
+    }
+    for item in list_items {
+        match item {
+            ListItem::List(item) => {
+                vec.append(&mut parse_to_number_list(item))
+            },
+            ListItem::Number(item) => {vec.push(*item as i32)},
+        }
+    }
+
+
+    vec
+}
+
+pub fn decoder_key(pairs: &[Pair]) -> u32 {
+    let divider_packer1 = vec![2];
+    let divider_packer2 = vec![6];
+    
+    let mut idx_divider_packer1 = 1;
+    let mut idx_divider_packer2 = 2;
+    
+    for (left, right) in pairs {
+        let parsed_left = parse_to_number_list(left);
+        let parsed_right = parse_to_number_list(right);
+
+        if divider_packer1 > parsed_left {idx_divider_packer1+=1;}
+        if divider_packer1 > parsed_right {idx_divider_packer1+=1;}
+        if divider_packer2 > parsed_left {idx_divider_packer2+=1;}
+        if divider_packer2 > parsed_right {idx_divider_packer2+=1;}
+    }
+    
+    idx_divider_packer1*idx_divider_packer2
+}
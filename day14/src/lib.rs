@@ -0,0 +1,130 @@
+use aoc_core::{lines, Grid, Offset, ParseError, Pos, Solution};
+use regex::Regex;
+
+const FALLING_FROM: usize = 500;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Grid<bool>, u32);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_grid(input)
+    }
+
+    fn part1((grid, min_x): &Self::Input) -> u32 {
+        solution_v1(grid.clone(), *min_x)
+    }
+
+    fn part2((grid, min_x): &Self::Input) -> u32 {
+        solution_v2(grid.clone(), *min_x)
+    }
+}
+
+pub fn get_grid(input: &str) -> Result<(Grid<bool>, u32), ParseError> {
+    let mut stone_ranges = vec![];
+    let (mut max_x, mut max_y, mut min_x) = (u32::MIN, u32::MIN, u32::MAX);
+    let mut last_vec;
+
+    let number_re = Regex::new(r"\d+,\d+").unwrap();
+    for line in lines(input) {
+
+        stone_ranges.push(vec![]);
+        last_vec = stone_ranges.last_mut().unwrap();
+
+        let coordinates = number_re
+        .find_iter(line.text).map(|number| number.as_str())
+        .collect::<Vec<&str>>();
+        if coordinates.is_empty() {return Err(line.missing("point"));}
+        for point in coordinates {
+            let (x, y) = point.split_once(',').unwrap();
+            let point = (line.parse::<u32>(x)?, line.parse::<u32>(y)?);
+            if point.0 > max_x {max_x = point.0}
+            if point.0 < min_x {min_x = point.0}
+            if point.1 > max_y {max_y = point.1}
+            last_vec.push(point);
+        }
+    }
+    if stone_ranges.is_empty() {
+        return Err(ParseError::MissingField { line: 1, column: 1, field: "rock path" });
+    }
+    min_x -= 1;
+    max_x += 1;
+    let diff_x = max_x - min_x;
+
+    // A row per depth, and a column per x from min_x
+    let mut grid = Grid::new((diff_x+1) as usize, (max_y+1) as usize, false);
+
+    for stone_range in stone_ranges {
+        let mut last_point = stone_range[0];
+        for point in stone_range {
+            let (from_x, to_x) = (last_point.0.min(point.0), last_point.0.max(point.0));
+            let (from_y, to_y) = (last_point.1.min(point.1), last_point.1.max(point.1));
+            for x in from_x..=to_x {
+                for y in from_y..=to_y {
+                    grid[Pos::new(y as usize, (x-min_x) as usize)] = true;
+                }
+            }
+            last_point = point;
+        }
+    }
+    
+    Ok((grid, min_x))
+
+}
+
+/// Drops sand from `falling_from` until it falls out of the bottom of the grid
+/// or piles up to `falling_from`.
+fn solution(mut grid: Grid<bool>, falling_from: Pos) -> (Grid<bool>, u32) {
+    let mut n_sands = 0;
+    let mut historical_pos = vec![falling_from];
+
+    'outer: while let Some(mut sand_pos) = historical_pos.pop() {
+        'inner: loop {
+            historical_pos.push(sand_pos);
+            if sand_pos.row == grid.height()-1 {
+                break 'outer;
+            }
+            let free = [Offset::DOWN, Offset::DOWN_LEFT, Offset::DOWN_RIGHT].into_iter()
+                .filter_map(|offset| grid.step(sand_pos, offset))
+                .find(|&below| !grid[below]);
+            match free {
+                Some(below) => sand_pos = below,
+                None => {
+                    // The sand found a spot
+                    grid[sand_pos] = true;
+                    n_sands += 1;
+                    if sand_pos == falling_from {break 'outer; }
+
+                    historical_pos.pop();
+                    break 'inner;
+                }
+            }
+        }
+    }
+
+    (grid, n_sands)
+}
+
+pub fn solution_v1(grid: Grid<bool>, min_x: u32) -> u32 {
+    let (_, n_sands) = solution(grid, Pos::new(0, FALLING_FROM-min_x as usize));
+    n_sands
+}
+
+pub fn solution_v2(mut grid: Grid<bool>, min_x: u32) -> u32 {
+    grid.add_rows(1, false);
+    grid.add_rows(1, true);
+    let (grid, mut n_sands) = solution(grid, Pos::new(0, FALLING_FROM-min_x as usize));
+
+    // The sand piling up beyond the sides of the grid forms triangles
+    let len_y = grid.height();
+    let index_left = grid.column(0).position(|&r| r).unwrap();
+    let index_right = grid.column(grid.width()-1).position(|&r| r).unwrap();
+
+    n_sands += (((len_y-index_left-2) * (len_y-index_left-1)) / 2) as u32;
+    n_sands += (((len_y-index_right-2) * (len_y-index_right-1)) / 2) as u32;
+
+    n_sands
+}
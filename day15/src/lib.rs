@@ -0,0 +1,149 @@
+use aoc_core::{lines, Interval, ParseError, Solution};
+use regex::Regex;
+use std::cmp::{min, max};
+
+/// Multiplier of the x coordinate in the tuning frequency.
+const TUNING_MULTIPLIER: i128 = 4000000;
+
+/// The row checked by the first part and the largest coordinate of the
+/// distress beacon searched by the second one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Area {
+    pub row: i32,
+    pub max_position: i32,
+}
+
+/// The area of the example, whose sensors all lie from 0 to 20.
+const EXAMPLE: Area = Area { row: 10, max_position: 20 };
+const PUZZLE: Area = Area { row: 2000000, max_position: 4000000 };
+
+/// The area of the example when every sensor lies within it, otherwise the
+/// one of the puzzle, which is far bigger.
+pub fn area(sensors: &[[i32; 4]]) -> Area {
+    let in_example = |coordinate: &i32| (0..=EXAMPLE.max_position).contains(coordinate);
+    if sensors.iter().all(|coordinates| coordinates[..2].iter().all(in_example)) {EXAMPLE} else {PUZZLE}
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<[i32; 4]>;
+    type Part1 = i32;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(sensors: &Self::Input) -> i32 {
+        get_non_beacon_positions(sensors, area(sensors).row)
+    }
+
+    fn part2(sensors: &Self::Input) -> i128 {
+        get_tuning_frequence(sensors, area(sensors).max_position)
+    }
+}
+
+/// Each sensor is stored as `[sensor_x, sensor_y, beacon_x, beacon_y]`.
+pub fn parse_input(input: &str) -> Result<Vec<[i32; 4]>, ParseError> {
+    let number_re = Regex::new(r"-?\d+").unwrap();
+
+    let mut sensors = vec![];
+    for line in lines(input) {
+        let mut numbers = number_re.find_iter(line.text).map(|number| number.as_str());
+        let mut coordinates = [0; 4];
+        for (coordinate, field) in coordinates.iter_mut().zip(["sensor x", "sensor y", "beacon x", "beacon y"]) {
+            *coordinate = line.parse_field(numbers.next(), field)?;
+        }
+        if let Some(extra) = numbers.next() {return Err(line.unexpected(extra));}
+        sensors.push(coordinates);
+    }
+
+    Ok(sensors)
+}
+
+pub fn get_non_beacon_positions(sensors: &[[i32; 4]], row: i32) -> i32 {
+    let mut intervals = vec![];
+
+    for coordinates in sensors {
+        let distance_to_beacon = (coordinates[0] - coordinates[2]).abs() + (coordinates[1] - coordinates[3]).abs();
+        let distance_to_row = (coordinates[1] - row).abs();
+
+        if distance_to_beacon > distance_to_row {
+            let diff_distances = distance_to_beacon - distance_to_row;
+            if coordinates[3] != row {
+                intervals.extend(Interval::new(coordinates[0] - diff_distances, coordinates[0] + diff_distances));
+            }
+            else {
+                if coordinates[0] > coordinates[2] {
+                    intervals.extend(Interval::new(coordinates[0] - diff_distances + 1, coordinates[0] + diff_distances));
+                }
+                else {
+                    intervals.extend(Interval::new(coordinates[0] - diff_distances, coordinates[0] + diff_distances - 1));
+                }
+            }
+        }
+    }
+
+    Interval::merge(intervals).iter().map(Interval::len).sum()
+}
+
+/// Tuning frequency of the only position from 0 to `max_position` on both
+/// axes that no sensor covers, or 0 if there is none.
+pub fn get_tuning_frequence(sensors: &[[i32; 4]], max_position: i32) -> i128 {
+    let mut possible_beacon_grid: Vec<Vec<Interval<i32>>> = vec![];
+    for _ in 0..=max_position {possible_beacon_grid.push(vec![])}
+
+    for coordinates in sensors {
+        let distance_to_beacon = (coordinates[0] - coordinates[2]).abs() + (coordinates[1] - coordinates[3]).abs();
+
+        for p in 0..distance_to_beacon+1 {
+            let q = (distance_to_beacon-p).abs();
+            let idx_minus = coordinates[1] - q;
+            let idx_plus = coordinates[1] + q;
+
+            let interval_min = max(coordinates[0]-p,0);
+            let interval_max = min(max_position, coordinates[0]+p);
+
+            // Nothing when the sensor range ends before 0 or starts after max_position
+            let interval = Interval::new(interval_min, interval_max);
+            if (0..=max_position).contains(&idx_minus) {
+                possible_beacon_grid[idx_minus as usize].extend(interval);
+            }
+            if (0..=max_position).contains(&idx_plus) {
+                possible_beacon_grid[idx_plus as usize].extend(interval);
+            }
+        }
+    }
+
+    let mut tuning_frequence = 0;
+    for (x, grid_intervals) in possible_beacon_grid.into_iter().enumerate() {
+        let merged_intervals = Interval::merge(grid_intervals);
+        if merged_intervals.len() > 1 {
+            tuning_frequence = (((merged_intervals[0].end() + merged_intervals[1].start())/2) as i128 * TUNING_MULTIPLIER) + x as i128;
+            break;
+        }
+    }
+    
+    tuning_frequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_the_example() {
+        let sensors = parse_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(area(&sensors), EXAMPLE);
+        assert_eq!(get_non_beacon_positions(&sensors, 10), 26);
+        assert_eq!(get_tuning_frequence(&sensors, 20), 56000011);
+    }
+
+    #[test]
+    fn sensors_beyond_the_example_are_the_puzzle() {
+        assert_eq!(area(&[[2, 18, -2, 15], [21, 0, 20, 0]]), PUZZLE);
+        assert_eq!(area(&[[-1, 0, 0, 0]]), PUZZLE);
+        assert_eq!(area(&[[20, 0, 25, 17]]), EXAMPLE);
+    }
+}
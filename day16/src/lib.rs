@@ -0,0 +1,225 @@
+use aoc_core::{lines, Line, ParseError, Solution};
+use regex::Regex;
+use std::collections::{HashMap, VecDeque};
+// use rayon::prelude::*;
+use itertools::Itertools;
+
+const MINUTES_LEFT:u32 = 30;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Vec<Valve>, usize);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((valves, start_at): &Self::Input) -> u32 {
+        solution_v1(valves, *start_at)
+    }
+
+    fn part2((valves, start_at): &Self::Input) -> u32 {
+        solution_v2(valves, *start_at)
+    }
+}
+
+#[derive(Debug)]
+pub struct Valve {
+    flow_rate: u32,
+    leads_to: Vec<usize>
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Valve>, usize), ParseError> {
+    let mut valve_name_to_number = HashMap::new();
+    let mut valves: Vec<Valve> = vec![];
+    let mut valve_names_exits: Vec<(Line, Vec<&str>)> = vec![];
+    let mut start_at = None;
+
+    let re = Regex::new(r"([A-Z][A-Z])|\d+").unwrap();
+    for (idx, line) in lines(input).enumerate() {
+        let mut line_splitted = re.find_iter(line.text)
+        .map(|digits| digits.as_str());
+
+        // Gets the name of the valve and assigns an idx to it
+        let valve_name = line.field(line_splitted.next(), "valve name")?;
+        if valve_name == "AA" {
+            start_at = Some(idx);
+        }
+        valve_name_to_number.insert(valve_name, idx);
+        
+
+        // Creates the valve with its flow rate
+        valves.push(Valve {
+            flow_rate: line.parse_field(line_splitted.next(), "flow rate")?, 
+            leads_to: vec![]
+        });
+
+        // Save the names of the exit valves
+        valve_names_exits.push((line, line_splitted.collect()));
+    }
+
+    for (valve, (line, valves_name)) in valves.iter_mut().zip(valve_names_exits) {
+        for valve_name in valves_name {
+            let valve_idx = valve_name_to_number.get(valve_name)
+                .ok_or_else(|| line.unexpected(valve_name))?;
+            valve.leads_to.push(*valve_idx);
+        }
+    }
+
+    let start_at = start_at
+        .ok_or(ParseError::MissingField { line: valves.len() + 1, column: 1, field: "valve AA" })?;
+
+    Ok((valves, start_at))
+
+}
+
+
+
+fn get_distance_matrix(valves: &[Valve], non_zero_valves: &[usize], start_at: usize) 
+-> Vec<Vec<u32>> {
+    let n = valves.len();
+    let mut distances = vec![vec![50; n]; n];
+    let mut queue = VecDeque::new();
+    let mut non_zero_valves = non_zero_valves.to_vec();
+    non_zero_valves.push(start_at);
+
+    // For each valve in the network...
+    for i in non_zero_valves.into_iter() {
+        
+        // Add the starting valve to the queue with a distance of 0.
+        queue.push_back((i, 0));
+        distances[i][i] = 0;
+
+        'queue: while let Some((valve_idx, distance)) = queue.pop_front() { 
+            if distance > MINUTES_LEFT-1 {break 'queue} // Means that is unreacheable in 30 times
+            for goes_to in &valves[valve_idx].leads_to {
+                let new_distance = distance + 1;
+                if new_distance < distances[i][*goes_to] {
+                    distances[i][*goes_to] = new_distance;
+                    queue.push_back((*goes_to, new_distance));
+                }
+            }
+        }
+    }
+
+    distances
+
+}
+
+fn compute_pressure(
+    valves: &[Valve], 
+    distance_matrix: &[Vec<u32>], 
+    permutation: Vec<usize>,
+    start_at: usize,
+    mut minutes_left: u32) -> u32 {
+
+    let mut pressure = 0;
+    let mut last_valve_idx = start_at;
+
+    for valve_idx in permutation {
+        if distance_matrix[last_valve_idx][valve_idx] >= minutes_left - 1 {break;}
+        minutes_left -= distance_matrix[last_valve_idx][valve_idx] + 1;
+        pressure += valves[valve_idx].flow_rate * minutes_left;
+        last_valve_idx = valve_idx;
+    }
+
+    pressure
+}
+
+fn compute_maximum_pressure_v1(
+    valves: &[Valve], 
+    distance_matrix: &[Vec<u32>], 
+    non_zero_valves: &[usize],
+    start_at: usize)
+    -> u32 {
+
+    let permutation_len = non_zero_valves.len()/2 + 1;
+
+
+    // Parallel version
+    // let maximum_pressure = non_zero_valves.clone().into_iter()
+    //     .permutations(permutation_len)
+    //     .par_bridge()
+    //     .map(|x| compute_pressure(valves, distance_matrix, x, start_at, MINUTES_LEFT))
+    //     .reduce(|| 0, |x, y| if x > y {return x} else {return y});
+
+    // Serial version
+    
+
+
+    non_zero_valves.iter().copied()
+        .permutations(permutation_len)
+        .map(|x| {compute_pressure(valves, distance_matrix, x, start_at, MINUTES_LEFT)})
+        .reduce(|x, y| if x > y {x} else {y}).unwrap()
+}
+
+fn compute_maximum_pressure_v2(
+    valves: &[Valve], 
+    distance_matrix: &[Vec<u32>], 
+    non_zero_valves: &[usize],
+    start_at: usize)
+    -> u32 {
+
+    let combinations = get_combinations(non_zero_valves, non_zero_valves.len()/2);
+    
+    let mut max = 0;
+    for (human_comb, eleph_comb) in combinations.iter() {
+
+        let maximum_pressure_human = human_comb.clone().into_iter()
+            .permutations(human_comb.len())
+            .map(|x| {compute_pressure(valves, distance_matrix, x, start_at, MINUTES_LEFT-4)})
+            .reduce(|x, y| if x > y {x} else {y}).unwrap();
+
+            
+        let maximum_pressure_elph = eleph_comb.clone().into_iter()
+            .permutations(eleph_comb.len())
+            .map(|x| {compute_pressure(valves, distance_matrix, x, start_at, MINUTES_LEFT-4)})
+            .reduce(|x, y| if x > y {x} else {y}).unwrap();
+
+        if maximum_pressure_elph + maximum_pressure_human > max {
+            max = maximum_pressure_elph + maximum_pressure_human;
+        }
+    }
+
+    max
+}
+
+fn get_non_zero_valves(valves: &[Valve]) -> Vec<usize> {
+    valves.iter().enumerate()
+        .filter_map(|(it, x)| if x.flow_rate != 0 {Some(it)} else {None})
+        .collect::<Vec<usize>>()
+}
+
+pub fn solution_v1(valves: &[Valve], start_at: usize) -> u32 {
+    let non_zero_valves = get_non_zero_valves(valves);
+
+    let distance_matrix = get_distance_matrix(valves, &non_zero_valves, start_at);
+
+    compute_maximum_pressure_v1(valves, &distance_matrix, &non_zero_valves, start_at)
+}
+
+pub fn solution_v2(valves: &[Valve], start_at: usize) -> u32 {
+    let non_zero_valves = get_non_zero_valves(valves);
+
+    let distance_matrix = get_distance_matrix(valves, &non_zero_valves, start_at);
+
+    compute_maximum_pressure_v2(valves, &distance_matrix, &non_zero_valves, start_at)
+}
+
+
+fn get_combinations(values: &[usize], n: usize) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let mut combinations = Vec::new();
+
+    for combination in values.iter().copied().combinations(n) {
+        let mut remaining_values = values.to_vec();
+        for value in combination.clone() {
+            remaining_values.retain(|&v| v != value);
+        }
+        combinations.push((combination, remaining_values));
+    }
+
+    combinations
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use aoc_core::{lines, Grid, ParseError, Pos, Solution};
+use std::collections::hash_map::{DefaultHasher, Entry};
+use std::hash::{Hash, Hasher};
+
+const CAVE_WIDTH:usize = 7;
+const ROCK_NUMBER_V1: usize = 2022;
+const ROCK_NUMBER_V2: usize = 1000000000000;
+
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(gas_directions: &Self::Input) -> usize {
+        solution_v1(gas_directions)
+    }
+
+    fn part2(gas_directions: &Self::Input) -> usize {
+        solution_v2(gas_directions)
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Right,
+    Left
+}
+
+struct RockProperties {
+    starting_position: (usize, usize),
+    left_right_bounds: (usize, usize),
+    rock_positions: Vec<(isize, isize)>,
+    rock_height: usize,
+}
+
+enum RockType {
+    HorizontalLine(RockProperties),
+    Plus(RockProperties),
+    InvertedL(RockProperties),
+    VerticalLine(RockProperties),
+    Squared(RockProperties)
+}
+const N_ROCK_TYPES: usize = 5;
+
+
+/// Reads the jet pattern, failing when it is empty as the rocks would have no
+/// jet to follow.
+pub fn parse_input(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut gas_directions = vec![];
+    for line in lines(input) {
+        for (column, ch) in (1..).zip(line.text.chars()) {
+            if ch == '<' {gas_directions.push(Direction::Left)}
+            else if ch == '>' {gas_directions.push(Direction::Right)}
+            else {return Err(line.unexpected_at(column, ch))}
+        }
+    }
+    if gas_directions.is_empty() {
+        return Err(ParseError::MissingField { line: 1, column: 1, field: "jet pattern" });
+    }
+
+    Ok(gas_directions)
+}
+
+fn create_rock_types() -> [RockType; N_ROCK_TYPES] {
+    let rock_types: [RockType; N_ROCK_TYPES] = [
+        RockType::HorizontalLine(RockProperties { 
+            starting_position: (2,3),
+            left_right_bounds: (0,3),
+            rock_positions: vec![(0,0), (1,0), (2,0), (3,0)],
+            rock_height: 1
+        }), 
+        RockType::Plus(RockProperties {  
+            starting_position: (3,3),
+            left_right_bounds: (1,5),
+            rock_positions: vec![(0,0), (0,1), (0,2), (-1,1),(1,1)],
+            rock_height: 3
+        }), 
+        RockType::InvertedL(RockProperties {  
+            starting_position: (2,3),
+            left_right_bounds: (0,4),
+            rock_positions: vec![(0,0), (1,0), (2,0), (2,1),(2,2)],
+            rock_height: 3
+        }), 
+        RockType::VerticalLine(RockProperties {  
+            starting_position: (2,3),
+            left_right_bounds: (0,6),
+            rock_positions: vec![(0,0), (0,1), (0,2), (0,3)],
+            rock_height: 4
+        }), 
+        RockType::Squared(RockProperties {  
+            starting_position: (2,3),
+            left_right_bounds: (0,5),
+            rock_positions: vec![(0,0), (0,1), (1,0), (1,1)],
+            rock_height: 2
+        }),
+    ];
+    rock_types
+}
+
+
+/// Keeps 7 empty rows above `height`, enough for any rock to appear. The cave
+/// has a row per height from the floor and a column per x.
+fn increase_cave_ceiling(grid: &mut Grid<bool>, height:usize) {
+    grid.add_rows(7 + height - grid.height(), false);
+}
+
+/// Cell of the cave at `x` and `y` from `rock_position`.
+fn cell(rock_position: (usize, usize), x: isize, y: isize) -> Pos {
+    Pos::new((rock_position.1 as isize + y) as usize, (rock_position.0 as isize + x) as usize)
+}
+
+fn can_move(grid: &Grid<bool>, rock_properties:&RockProperties, rock_position: (usize, usize), direction: Direction) -> bool {
+
+    match direction {
+        Direction::Left => {
+            if rock_position.0 > rock_properties.left_right_bounds.0 {
+                for rock_relative_position in &rock_properties.rock_positions {
+                    let (x, y) = rock_relative_position;
+                    if grid[cell(rock_position, x - 1, *y)] {
+                        return false
+                    }
+                }
+            }
+            else {return false;}
+        }
+        Direction::Right => {
+            if rock_position.0 < rock_properties.left_right_bounds.1 {
+                for rock_relative_position in &rock_properties.rock_positions {
+                    let (x, y) = rock_relative_position;
+                    if grid[cell(rock_position, x + 1, *y)] {
+                        return false
+                    }
+                }
+            }
+            else {return false;}
+        }
+    }
+
+    true
+}
+
+pub fn solution_v1(gas_directions: &[Direction]) -> usize {
+
+    let mut grid = Grid::new(CAVE_WIDTH, 0, false);
+
+    let mut gas_idx = 0;
+    let mut height = 0;
+    increase_cave_ceiling(&mut grid, height);
+
+    let mut current_rock_type;
+    let rock_types = create_rock_types();
+    for resting_rocks in 0..ROCK_NUMBER_V1 {
+        current_rock_type = &rock_types[resting_rocks%N_ROCK_TYPES];
+
+        let mut rock_position;
+
+        match current_rock_type {
+            RockType::HorizontalLine(rock_properties) |
+            RockType::Plus(rock_properties) |
+            RockType::InvertedL(rock_properties) |
+            RockType::VerticalLine(rock_properties) |
+            RockType::Squared(rock_properties) => {
+
+                rock_position = (rock_properties.starting_position.0, rock_properties.starting_position.1+height);
+                'rock_falling: loop {
+                    match gas_directions[gas_idx%gas_directions.len()] {
+                        Direction::Left => {
+                            if can_move(&grid, rock_properties, rock_position, Direction::Left) {rock_position.0 -= 1;}
+                        }
+                        Direction::Right => {
+                            if can_move(&grid, rock_properties, rock_position, Direction::Right) {rock_position.0 += 1;}
+                        } 
+                    }
+                    gas_idx += 1;
+
+                    
+
+                    for rock_relative_position in &rock_properties.rock_positions {
+                        let (x, y) = rock_relative_position;
+                        if rock_position.1 == 0 || grid[cell(rock_position, *x, y - 1)] {
+                            
+                            // The rock has found a place to stay
+
+                            if rock_position.1 + rock_properties.rock_height > height {
+                                height = rock_position.1 + rock_properties.rock_height;
+                            }
+                            
+                            increase_cave_ceiling(&mut grid, height);
+
+                            // We add the rock to the grid
+                            for rock_relative_position in &rock_properties.rock_positions {
+                                let (x, y) = rock_relative_position;
+                                grid[cell(rock_position, *x, *y)] = true;
+                            }
+
+                            break 'rock_falling;
+                        }
+                    }
+                    rock_position.1 -= 1;
+                }
+            }
+        }
+    }
+    height
+}
+
+
+fn calculate_hash(grid: &Grid<bool>, gas_idx: usize) -> u64 {
+    let len = grid.height();
+    let mut s = DefaultHasher::new();
+    if len > 20 {
+        for row in grid.rows().skip(len-20) {
+            row.hash(&mut s);
+        }
+    }
+    gas_idx.hash(&mut s);
+    // t.hash(&mut s);
+    s.finish()
+}
+
+
+pub fn solution_v2(gas_directions: &[Direction]) -> usize {
+
+    let mut grid = Grid::new(CAVE_WIDTH, 0, false);
+    let mut grid_and_gas_snapshot = HashMap::new();
+    let mut computed_height_by_repetition = 0;
+
+    let mut gas_idx = 0;
+    let mut height = 0;
+    increase_cave_ceiling(&mut grid, height);
+
+    let mut current_rock_type;
+    let rock_types = create_rock_types();
+    let mut resting_rocks = 0;
+    while resting_rocks < ROCK_NUMBER_V2 {
+        current_rock_type = &rock_types[resting_rocks%N_ROCK_TYPES];
+
+        let mut rock_position;
+
+        match current_rock_type {
+            RockType::HorizontalLine(rock_properties) |
+            RockType::Plus(rock_properties) |
+            RockType::InvertedL(rock_properties) |
+            RockType::VerticalLine(rock_properties) |
+            RockType::Squared(rock_properties) => {
+
+                rock_position = (rock_properties.starting_position.0, rock_properties.starting_position.1+height);
+                'rock_falling: loop {
+                    match gas_directions[gas_idx%gas_directions.len()] {
+                        Direction::Left => {
+                            if can_move(&grid, rock_properties, rock_position, Direction::Left) {rock_position.0 -= 1;}
+                        }
+                        Direction::Right => {
+                            if can_move(&grid, rock_properties, rock_position, Direction::Right) {rock_position.0 += 1;}
+                        } 
+                    }
+                    gas_idx += 1;
+
+                    
+
+                    for rock_relative_position in &rock_properties.rock_positions {
+                        let (x, y) = rock_relative_position;
+                        if rock_position.1 == 0 || grid[cell(rock_position, *x, y - 1)] {
+                            
+                            // The rock has found a place to stay
+
+                            if rock_position.1 + rock_properties.rock_height > height {
+                                height = rock_position.1 + rock_properties.rock_height;
+                            }
+                            
+                            increase_cave_ceiling(&mut grid, height);
+
+                            // We add the rock to the grid
+                            for rock_relative_position in &rock_properties.rock_positions {
+                                let (x, y) = rock_relative_position;
+                                grid[cell(rock_position, *x, *y)] = true;
+                            }
+
+                            resting_rocks += 1;
+                            break 'rock_falling;
+                        }
+                    }
+                    rock_position.1 -= 1;
+                }
+            }
+        }
+        match grid_and_gas_snapshot.entry(calculate_hash(&grid, gas_idx%gas_directions.len())) {
+            Entry::Occupied(o) => {
+                let (prev_height, prev_n_rock) = o.get();                
+
+                let height_diff = height - prev_height;
+                let n_rock_diff = resting_rocks - prev_n_rock;
+
+                let rock_repeats = (ROCK_NUMBER_V2 - resting_rocks) / n_rock_diff;
+                resting_rocks += rock_repeats * n_rock_diff;
+                computed_height_by_repetition += rock_repeats * height_diff;
+
+            },
+            Entry::Vacant(v) => {
+                v.insert((height, resting_rocks));
+            },
+        };
+    }
+
+    height + computed_height_by_repetition
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_an_empty_jet_pattern() {
+        assert!(matches!(parse_input(""), Err(ParseError::MissingField { line: 1, column: 1, field: "jet pattern" })));
+        assert!(matches!(parse_input("\n"), Err(ParseError::MissingField { field: "jet pattern", .. })));
+    }
+
+    #[test]
+    fn solves_the_example() {
+        let gas_directions = parse_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(solution_v1(&gas_directions), 3068);
+        assert_eq!(solution_v2(&gas_directions), 1514285714288);
+    }
+}
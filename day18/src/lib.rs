@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+use aoc_core::{lines, Grid, ParseError, Pos, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(i32, i32, i32)>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cubes_coordinates: &Self::Input) -> usize {
+        solution_v1(cubes_coordinates)
+    }
+
+    fn part2(cubes_coordinates: &Self::Input) -> u32 {
+        solution_v2(cubes_coordinates)
+    }
+}
+
+#[derive(Clone)]
+
+enum LavaDroplet {
+    Lava,
+    Water,
+    Air
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(i32, i32, i32)>, ParseError> {
+    let mut cubes_coordinates = vec![];
+    for line in lines(input) {
+        let mut number_it = line.text.split(',');
+        let mut coordinates = [0; 3];
+        for (coordinate, field) in coordinates.iter_mut().zip(["x", "y", "z"]) {
+            let number = line.field(number_it.next(), field)?;
+            *coordinate = line.parse(number)?;
+        }
+        if let Some(extra) = number_it.next() {return Err(line.unexpected(extra));}
+        cubes_coordinates.push((coordinates[0], coordinates[1], coordinates[2]));
+    }
+
+    Ok(cubes_coordinates)
+}
+
+pub fn solution_v1(cubes_coordinates: &[(i32, i32, i32)]) -> usize {
+
+    let mut lava_cubes = HashSet::new();
+    let mut total_surface_area = 0;
+
+    for &coordinates in cubes_coordinates {
+        total_surface_area += 6;
+        lava_cubes.insert(coordinates);
+
+        for (x,y,z) in [(1,0,0), (0,1,0), (0,0,1), (-1,0,0), (0,-1,0), (0,0,-1)] {
+            if lava_cubes.contains(&(coordinates.0+x, coordinates.1+y, coordinates.2+z)) {
+                total_surface_area -= 2;  
+            }
+        }
+    }
+
+    total_surface_area
+}
+
+/// A layer of the droplet per z, with a row per x and a column per y.
+type Layers = Vec<Grid<LavaDroplet>>;
+
+/// Lowest and highest x, y and z of the cubes of the droplet.
+fn bounds(cubes_coordinates: &[(i32, i32, i32)]) -> Option<[(i32, i32); 3]> {
+    let (&(x, y, z), others) = cubes_coordinates.split_first()?;
+    Some(others.iter().fold([(x, x), (y, y), (z, z)], |mut bounds, &(x, y, z)| {
+        for ((min, max), coordinate) in bounds.iter_mut().zip([x, y, z]) {
+            *min = (*min).min(coordinate);
+            *max = (*max).max(coordinate);
+        }
+        bounds
+    }))
+}
+
+/// The droplet in the smallest box holding it, whose corner is the lowest x,
+/// y and z of its cubes.
+fn build_grid(cubes_coordinates: &[(i32, i32, i32)]) -> Layers {
+    let Some(bounds) = bounds(cubes_coordinates) else {return vec![]};
+    let [rows, columns, layers] = bounds.map(|(min, max)| max.abs_diff(min) as usize + 1);
+    let mut grid = vec![Grid::new(columns, rows, LavaDroplet::Air); layers];
+
+    let [(x0, _), (y0, _), (z0, _)] = bounds;
+    for &(x, y, z) in cubes_coordinates {
+        grid[z.abs_diff(z0) as usize][Pos::new(x.abs_diff(x0) as usize, y.abs_diff(y0) as usize)] = LavaDroplet::Lava;
+    }
+
+    grid
+}
+
+/// The cubes sharing a face with the cube at `pos` of the layer `z`.
+fn get_water_changes(grid: &Layers, pos: Pos, z: usize) -> Vec<(Pos, usize)> {
+    let mut water_changes: Vec<(Pos, usize)> = grid[z].neighbours(pos).map(|next| (next, z)).collect();
+    if z > 0 {water_changes.push((pos, z - 1))}
+    if z < grid.len()-1 {water_changes.push((pos, z + 1))}
+
+    water_changes
+}
+
+/// Fills with water the air connected to the cube at `pos` of the layer `z`,
+/// returning the number of lava faces the water touches.
+fn fill(grid: &mut Layers, pos: Pos, z: usize) -> u32 {
+    // A stack rather than recursion, as the droplet may be big
+    let mut sum = 0;
+    let mut to_fill = vec![(pos, z)];
+    while let Some((pos, z)) = to_fill.pop() {
+        match grid[z][pos] {
+            LavaDroplet::Air => {
+                grid[z][pos] = LavaDroplet::Water;
+                to_fill.extend(get_water_changes(grid, pos, z));
+            }
+            LavaDroplet::Lava => {sum += 1}
+            LavaDroplet::Water => {}
+        }
+    }
+    sum
+}
+
+pub fn solution_v2(cubes_coordinates: &[(i32, i32, i32)]) -> u32 {
+
+    let mut grid = build_grid(cubes_coordinates);
+    let layers = grid.len();
+    let (rows, columns) = grid.first().map_or((0, 0), |layer| (layer.height(), layer.width()));
+
+    // Flood fill algorithm, from every face of the box, where the lava
+    // touching a face counts that face
+    let mut sum = 0;
+    for x in 0..rows {
+        for y in 0..columns {
+            sum += fill(&mut grid, Pos::new(x, y), 0);
+            sum += fill(&mut grid, Pos::new(x, y), layers-1);
+        }
+        for z in 0..layers {
+            sum += fill(&mut grid, Pos::new(x, 0), z);
+            sum += fill(&mut grid, Pos::new(x, columns-1), z);
+        }
+    }
+    for y in 0..columns {
+        for z in 0..layers {
+            sum += fill(&mut grid, Pos::new(0, y), z);
+            sum += fill(&mut grid, Pos::new(rows-1, y), z);
+        }
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_the_example() {
+        let cubes_coordinates = parse_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(solution_v1(&cubes_coordinates), 64);
+        assert_eq!(solution_v2(&cubes_coordinates), 58);
+    }
+
+    #[test]
+    fn the_grid_fits_the_droplet() {
+        assert_eq!(solution_v2(&[(0, 0, 0)]), 6);
+        assert_eq!(solution_v2(&[(40, 3, 0), (40, 3, 1)]), 10);
+        assert_eq!(solution_v2(&[]), 0);
+        let grid = build_grid(&[(1000, 1000, 1000), (1001, 1000, 1002)]);
+        assert_eq!((grid.len(), grid[0].height(), grid[0].width()), (3, 2, 1));
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let cubes_coordinates = parse_input("-1,-2,-3\n-1,-2,-2\n-1,-2,-4").unwrap();
+        assert_eq!(solution_v1(&cubes_coordinates), 14);
+        assert_eq!(solution_v2(&cubes_coordinates), 14);
+        assert!(matches!(parse_input("1,-x,2"), Err(ParseError::UnexpectedToken { line: 1, column: 3, .. })));
+    }
+
+    #[test]
+    fn air_pockets_do_not_count() {
+        // A hollow 3x3x3 cube, with a single pocket of air in the middle
+        let cubes_coordinates: Vec<(i32, i32, i32)> = (0..27)
+            .map(|idx| (idx % 3, idx / 3 % 3, idx / 9))
+            .filter(|&cube| cube != (1, 1, 1))
+            .collect();
+        assert_eq!(solution_v1(&cubes_coordinates), 54 + 6);
+        assert_eq!(solution_v2(&cubes_coordinates), 54);
+    }
+}
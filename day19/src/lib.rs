@@ -0,0 +1,227 @@
+use aoc_core::{lines, ParseError, Solution};
+use regex::Regex;
+use std::cmp;
+
+const MINUTES_LEFT_V1:i32 = 24;
+const MINUTES_LEFT_V2:i32 = 32;
+/// Blueprints left uneaten by the elephants, or fewer if the list is shorter.
+const BLUEPRINTS_V2: usize = 3;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<RobotCosts>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(robots_costs: &Self::Input) -> i32 {
+        solution_v1(robots_costs)
+    }
+
+    fn part2(robots_costs: &Self::Input) -> i32 {
+        solution_v2(robots_costs)
+    }
+}
+
+#[derive(Debug)]
+pub struct RobotCosts {
+    ore: i32,
+    clay: i32,
+    obsidian: (i32, i32),
+    geode: (i32, i32)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<RobotCosts>, ParseError> {
+    let mut robots_cost = vec![];
+    let re = Regex::new(r"\d+").unwrap();
+
+    for line in lines(input) {
+        // Blueprint id, ore, clay, obsidian (ore and clay) and geode (ore and obsidian)
+        let mut numbers = re.find_iter(line.text).map(|x| x.as_str());
+        let mut costs = [0; 7];
+        for cost in costs.iter_mut() {
+            *cost = line.parse_field(numbers.next(), "robot cost")?;
+        }
+        if let Some(extra) = numbers.next() {return Err(line.unexpected(extra));}
+
+        robots_cost.push(RobotCosts {
+            ore: costs[1],
+            clay: costs[2],
+            obsidian: (costs[3], costs[4]),
+            geode: (costs[5], costs[6])
+        })
+    }
+
+    Ok(robots_cost)
+}
+
+pub fn div_up(a: i32, b: i32) -> i32 {
+    (a + (b - 1))/b
+}
+
+fn _max_geode(robot_cost: &RobotCosts, mut minutes_left:i32, mut materials: [i32; 4], mut robots: [i32; 4], next_robot:usize, prunning_treshold: i32) -> i32 {
+
+    let minutes_needed;
+    match next_robot {
+        0 => {
+
+            let ore_needed = cmp::max(robot_cost.ore - materials[0], 0);
+            minutes_needed = div_up(ore_needed, robots[0]) + 1;
+
+            // No time enough for the robot to produce anything
+            if minutes_needed > minutes_left {
+                return materials[3] + minutes_left*robots[3];
+            }
+
+            materials[0] -= robot_cost.ore;
+
+        },
+        1 => {
+
+            let ore_needed = cmp::max(robot_cost.clay - materials[0],0);
+            minutes_needed = div_up(ore_needed, robots[0]) + 1;
+
+            // No time enough for the robot to produce anything
+            if minutes_needed > minutes_left {
+                return materials[3] + minutes_left*robots[3];
+            }
+
+            materials[0] -= robot_cost.clay;
+        }
+        2 => {
+
+            // Compute time needed to create that robot
+            let ore_needed = cmp::max(robot_cost.obsidian.0 - materials[0],0);
+            let clay_needed = cmp::max(robot_cost.obsidian.1 - materials[1],0);
+
+            let time_needed_1 = div_up(ore_needed, robots[0]) + 1;
+            let time_needed_2 = div_up(clay_needed, robots[1]) + 1;
+
+            minutes_needed = cmp::max(time_needed_1, time_needed_2);
+
+            // No time enough for the robot to produce anything
+            if minutes_needed > minutes_left {
+                return materials[3] + minutes_left*robots[3];
+            }
+
+            materials[0] -= robot_cost.obsidian.0;
+            materials[1] -= robot_cost.obsidian.1;
+        }
+        3 => {
+
+            // Compute time needed to create that robo
+            let ore_needed = cmp::max(robot_cost.geode.0 - materials[0],0);
+            let obsidian_needed = cmp::max(robot_cost.geode.1 - materials[2],0);
+
+            let time_needed_1 = div_up(ore_needed, robots[0]) + 1;
+            let time_needed_2 = div_up(obsidian_needed, robots[2]) + 1;
+
+            minutes_needed = cmp::max(time_needed_1, time_needed_2);
+
+            // No time enough for the robot to produce anything
+            if minutes_needed > minutes_left {
+                return materials[3] + minutes_left*robots[3];
+            }
+
+            materials[0] -= robot_cost.geode.0;
+            materials[2] -= robot_cost.geode.1;
+        }
+        _ => panic!("Next robot can only be [0,1,2,3]")
+    }
+
+    // Take the time
+    minutes_left -= minutes_needed;
+
+    // Increase the materials
+    for i in 0..4 {
+        materials[i] += robots[i] * minutes_needed;
+    }
+
+    // Create the robot
+    robots[next_robot] += 1;
+
+    
+    if minutes_left < prunning_treshold && robots[3] == 0 {
+        return 0;
+    }
+
+    let max_geodes;
+    if next_robot == 0 {
+        let geodes_0 = _max_geode(robot_cost, minutes_left, materials, robots, 0, prunning_treshold);
+        let geodes_1 = _max_geode(robot_cost, minutes_left, materials, robots, 1, prunning_treshold);
+        max_geodes = cmp::max(geodes_0, geodes_1);
+    }
+    else if next_robot == 1 {
+        let geodes_0 = _max_geode(robot_cost, minutes_left, materials, robots, 0, prunning_treshold);
+        let geodes_1 = _max_geode(robot_cost, minutes_left, materials, robots, 1, prunning_treshold);
+        let geodes_2 = _max_geode(robot_cost, minutes_left, materials, robots, 2, prunning_treshold);
+        max_geodes = cmp::max(geodes_0, cmp::max(geodes_1, geodes_2));
+    }
+    else if next_robot == 2 {
+        let geodes_1 = _max_geode(robot_cost, minutes_left, materials, robots, 1,prunning_treshold);
+        let geodes_2 = _max_geode(robot_cost, minutes_left, materials, robots, 2, prunning_treshold);
+        let geodes_3 = _max_geode(robot_cost, minutes_left, materials, robots, 3, prunning_treshold);
+        max_geodes = cmp::max(geodes_1, cmp::max(geodes_2, geodes_3));
+    }
+    else {
+        let geodes_2 = _max_geode(robot_cost, minutes_left, materials, robots, 2, prunning_treshold);
+        let geodes_3 = _max_geode(robot_cost, minutes_left, materials, robots, 3, prunning_treshold);
+        max_geodes = cmp::max(geodes_2, geodes_3);
+    }
+
+    max_geodes
+
+}
+
+fn max_geodes(robot_cost: &RobotCosts, minutes_left:i32, prunning_treshold:i32) -> i32 {
+
+    let materials = [0,0,0,0];
+    let robots = [1,0,0,0];
+
+
+    // Compute all possible paths and return the best one
+    let max_geodes_0 = _max_geode(robot_cost, minutes_left, materials, robots, 0, prunning_treshold);
+
+    let max_geodes_1 = _max_geode(robot_cost, minutes_left, materials, robots, 1, prunning_treshold);
+
+    cmp::max(max_geodes_0, max_geodes_1)
+}
+
+pub fn solution_v1(robots_costs: &[RobotCosts]) -> i32 {
+
+    let mut sum = 0;
+    for (it, robot_cost) in robots_costs.iter().enumerate() {
+        sum += max_geodes(robot_cost, MINUTES_LEFT_V1, 5) * (it+1) as i32;
+    }
+
+    sum
+}
+
+pub fn solution_v2(robots_costs: &[RobotCosts]) -> i32 {
+    let mut prod = 1;
+    for robot_cost in robots_costs.iter().take(BLUEPRINTS_V2) {
+        prod *= max_geodes(robot_cost, MINUTES_LEFT_V2, 7);
+    }
+
+    prod
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fewer_blueprints_than_the_elephants_leave() {
+        // Robots too expensive to ever build a geode one
+        let robots_costs = parse_input("Blueprint 1: Each ore robot costs 40 ore. \
+            Each clay robot costs 40 ore. Each obsidian robot costs 40 ore and 40 clay. \
+            Each geode robot costs 40 ore and 40 obsidian.").unwrap();
+        assert_eq!(solution_v2(&robots_costs), 0);
+        assert_eq!(solution_v2(&[]), 1);
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, DEFAULT_INPUT};
+use clap::Parser;
+use day2::{parse_guide, Evaluation, Guide, Interpretation, Rules};
+
+/// Searches for the ways of reading the response column of a strategy guide
+/// that score the most and the least.
+#[derive(Parser)]
+struct Args {
+    /// Strategy guide
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+
+    /// Rules of the game, in TOML. Defaults to rock, paper, scissors
+    #[arg(long)]
+    rules: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+    let rules = match &args.rules {
+        Some(path) => match Rules::load(path) {
+            Ok(rules) => rules,
+            Err(error) => {
+                eprintln!("{}: {error}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Rules::standard(),
+    };
+
+    let guide = match parse_guide(&input, rules) {
+        Ok(guide) => guide,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (interpretation, description) in [
+        (Interpretation::Move, "Responses read as moves"),
+        (Interpretation::Outcome, "Responses read as outcomes"),
+    ] {
+        println!("{description}:");
+        if let Some(evaluation) = guide.rules_mapping(interpretation).and_then(|mapping| guide.evaluate(&mapping)) {
+            print_evaluation(&guide, "guide", &evaluation);
+        }
+        match guide.optimize(interpretation) {
+            Some(optimum) => {
+                print_evaluation(&guide, "best", &optimum.best);
+                print_evaluation(&guide, "worst", &optimum.worst);
+            }
+            None => println!("  there are more response symbols than meanings"),
+        }
+        println!();
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn print_evaluation(guide: &Guide, description: &str, evaluation: &Evaluation) {
+    println!("  {description:<5} {}: {} ({:.1} against a random opponent)",
+        evaluation.mapping.display(&guide.rules), evaluation.score, evaluation.expected);
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, report, DEFAULT_INPUT};
+use clap::Parser;
+use day2::{parse_guide, Interpretation, Rules};
+
+/// Scores a strategy guide with the rules of another game, such as
+/// `rules/rpsls.toml`.
+#[derive(Parser)]
+struct Args {
+    /// Strategy guide
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+
+    /// Rules of the game, in TOML
+    #[arg(long)]
+    rules: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+
+    let rules = match Rules::load(&args.rules) {
+        Ok(rules) => rules,
+        Err(error) => {
+            eprintln!("{}: {error}", args.rules.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let names = rules.moves().iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+    println!("Playing {}\n", names.join(", "));
+
+    let guide = match parse_guide(&input, rules) {
+        Ok(guide) => guide,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (interpretation, description) in [
+        (Interpretation::Move, "Your score when responses are moves:"),
+        (Interpretation::Outcome, "Your score when responses are outcomes:"),
+    ] {
+        match guide.score(interpretation) {
+            Ok(score) => report(description, score),
+            Err(error) => report(description, format!("none, {error}")),
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,85 @@
+use aoc_core::{lines, ParseError, Solution};
+
+mod optimize;
+mod rules;
+
+pub use optimize::{Evaluation, Mapping, Meaning, Optimum};
+pub use rules::{Interpretation, Move, Outcome, Rules, RulesError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Guide;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(guide: &Self::Input) -> u32 {
+        guide.score(Interpretation::Move)
+            .expect("the standard rules read every response as a move")
+    }
+
+    fn part2(guide: &Self::Input) -> u32 {
+        guide.score(Interpretation::Outcome)
+            .expect("the standard rules read every response as an outcome")
+    }
+}
+
+/// A round of the strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    /// Move played by the opponent.
+    pub opponent: usize,
+    /// Symbol of the response column, read as a move or as an outcome
+    /// depending on the interpretation.
+    pub response: String,
+    /// Position of the response in the guide, to report it.
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A strategy guide, together with the rules of the game it is for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guide {
+    pub rules: Rules,
+    pub rounds: Vec<Round>,
+}
+
+impl Guide {
+    /// Total score of following the guide, reading its responses as
+    /// `interpretation`.
+    pub fn score(&self, interpretation: Interpretation) -> Result<u32, ParseError> {
+        self.rules.score(&self.rounds, interpretation)
+    }
+}
+
+/// Returns the strategy guide for rock, paper, scissors.
+pub fn parse_input(input: &str) -> Result<Guide, ParseError> {
+    parse_guide(input, Rules::standard())
+}
+
+/// Returns the strategy guide for the game of `rules`, as (opponent, response)
+/// columns. Responses must mean a move or an outcome.
+pub fn parse_guide(input: &str, rules: Rules) -> Result<Guide, ParseError> {
+    let mut rounds = vec![];
+    for line in lines(input) {
+        let mut columns = line.text.split_whitespace();
+        let opponent = line.field(columns.next(), "opponent choice")?;
+        let response = line.field(columns.next(), "response")?;
+        let opponent = rules.opponent_move(opponent).ok_or_else(|| line.unexpected(opponent))?;
+        if rules.response_move(response).is_none() && rules.response_outcome(response).is_none() {
+            return Err(line.unexpected(response));
+        }
+        if let Some(extra) = columns.next() {return Err(line.unexpected(extra));}
+        rounds.push(Round {
+            opponent,
+            response: response.to_string(),
+            line: line.number,
+            column: line.column(response),
+        });
+    }
+    Ok(Guide { rules, rounds })
+}
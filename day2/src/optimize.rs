@@ -0,0 +1,204 @@
+use std::fmt::{self, Display};
+use itertools::Itertools;
+
+use crate::{Guide, Interpretation, Outcome, Rules};
+
+/// What a symbol of the response column is read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Meaning {
+    /// The move to play.
+    Move(usize),
+    /// The outcome to reach.
+    Outcome(Outcome),
+}
+
+/// A way of reading each symbol of the response column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub meanings: Vec<(String, Meaning)>,
+}
+
+impl Mapping {
+    /// Meaning of the response `symbol`.
+    pub fn meaning(&self, symbol: &str) -> Option<Meaning> {
+        self.meanings.iter().find(|(s, _)| s == symbol).map(|&(_, meaning)| meaning)
+    }
+
+    /// Names the meanings after the moves of `rules`.
+    pub fn display<'a>(&'a self, rules: &'a Rules) -> impl Display + 'a {
+        DisplayMapping { mapping: self, rules }
+    }
+}
+
+struct DisplayMapping<'a> {
+    mapping: &'a Mapping,
+    rules: &'a Rules,
+}
+
+impl Display for DisplayMapping<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, (symbol, meaning)) in self.mapping.meanings.iter().enumerate() {
+            if idx > 0 {write!(f, ", ")?;}
+            match meaning {
+                Meaning::Move(mine) => write!(f, "{symbol}={}", self.rules.moves()[*mine].name)?,
+                Meaning::Outcome(outcome) => write!(f, "{symbol}={outcome}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Scores of following a strategy guide read with `mapping`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation {
+    pub mapping: Mapping,
+    /// Total score against the moves of the guide.
+    pub score: u32,
+    /// Average total score against an opponent playing each move at random.
+    pub expected: f64,
+}
+
+/// The best and the worst ways of reading a strategy guide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optimum {
+    pub best: Evaluation,
+    pub worst: Evaluation,
+}
+
+impl Guide {
+    /// Symbols of the response column, sorted.
+    pub fn response_symbols(&self) -> Vec<&str> {
+        self.rounds.iter().map(|round| round.response.as_str()).unique().sorted().collect()
+    }
+
+    /// Points for answering `theirs` with a response read as `meaning`.
+    fn round_score(&self, meaning: Meaning, theirs: usize) -> u32 {
+        let mine = match meaning {
+            Meaning::Move(mine) => mine,
+            Meaning::Outcome(outcome) => self.rules.move_for(theirs, outcome),
+        };
+        self.rules.round_score(mine, theirs)
+    }
+
+    /// Scores of following the guide with its responses read as `mapping`,
+    /// or `None` if `mapping` gives no meaning to one of them.
+    pub fn evaluate(&self, mapping: &Mapping) -> Option<Evaluation> {
+        let moves = self.rules.moves().len();
+        let mut score = 0;
+        let mut expected = 0.;
+        for round in &self.rounds {
+            let meaning = mapping.meaning(&round.response)?;
+            score += self.round_score(meaning, round.opponent);
+            expected += (0..moves).map(|theirs| self.round_score(meaning, theirs)).sum::<u32>() as f64 / moves as f64;
+        }
+        Some(Evaluation { mapping: mapping.clone(), score, expected })
+    }
+
+    /// How the rules read the responses as `interpretation`, or `None` if
+    /// they cannot read all of them that way.
+    pub fn rules_mapping(&self, interpretation: Interpretation) -> Option<Mapping> {
+        let meanings = self.response_symbols().into_iter()
+            .map(|symbol| {
+                let meaning = match interpretation {
+                    Interpretation::Move => Meaning::Move(self.rules.response_move(symbol)?),
+                    Interpretation::Outcome => Meaning::Outcome(self.rules.response_outcome(symbol)?),
+                };
+                Some((symbol.to_string(), meaning))
+            })
+            .collect::<Option<_>>()?;
+        Some(Mapping { meanings })
+    }
+
+    /// Every way of giving a distinct meaning of `interpretation` to each
+    /// response symbol, such as the 6 ways of reading X, Y and Z as rock,
+    /// paper and scissors.
+    pub fn mappings(&self, interpretation: Interpretation) -> Vec<Mapping> {
+        let meanings: Vec<Meaning> = match interpretation {
+            Interpretation::Move => (0..self.rules.moves().len()).map(Meaning::Move).collect(),
+            Interpretation::Outcome => Outcome::ALL.into_iter().map(Meaning::Outcome).collect(),
+        };
+        let symbols = self.response_symbols();
+
+        meanings.into_iter().permutations(symbols.len())
+            .map(|meanings| Mapping {
+                meanings: symbols.iter().map(|symbol| symbol.to_string()).zip(meanings).collect(),
+            })
+            .collect()
+    }
+
+    /// The mappings of `interpretation` giving the highest and the lowest
+    /// total scores, or `None` when there are more response symbols than
+    /// meanings. Between mappings scoring as much, the first enumerated wins.
+    pub fn optimize(&self, interpretation: Interpretation) -> Option<Optimum> {
+        let evaluations = self.mappings(interpretation).into_iter()
+            .map(|mapping| self.evaluate(&mapping).unwrap())
+            .collect::<Vec<_>>();
+
+        let best = evaluations.iter().rev().max_by_key(|evaluation| evaluation.score)?.clone();
+        let worst = evaluations.iter().min_by_key(|evaluation| evaluation.score)?.clone();
+        Some(Optimum { best, worst })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    const GUIDE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn enumerates_every_mapping() {
+        let guide = parse_input(GUIDE).unwrap();
+        let mappings = guide.mappings(Interpretation::Move);
+        assert_eq!(mappings.len(), 6);
+        assert_eq!(mappings[0], guide.rules_mapping(Interpretation::Move).unwrap());
+    }
+
+    #[test]
+    fn best_and_worst_moves() {
+        let guide = parse_input(GUIDE).unwrap();
+        let optimum = guide.optimize(Interpretation::Move).unwrap();
+        assert_eq!(optimum.best.mapping.display(&guide.rules).to_string(), "X=scissors, Y=paper, Z=rock");
+        assert_eq!(optimum.best.score, 24);
+        assert_eq!(optimum.worst.mapping.display(&guide.rules).to_string(), "X=rock, Y=scissors, Z=paper");
+        assert_eq!(optimum.worst.score, 6);
+        // Every move is played once against each random opponent move
+        assert_eq!(optimum.best.expected, 15.);
+        assert_eq!(optimum.worst.expected, 15.);
+    }
+
+    #[test]
+    fn best_and_worst_outcomes() {
+        let guide = parse_input(GUIDE).unwrap();
+        let optimum = guide.optimize(Interpretation::Outcome).unwrap();
+        assert_eq!(optimum.best.mapping.display(&guide.rules).to_string(), "X=win, Y=lose, Z=draw");
+        assert_eq!(optimum.best.score, 18);
+        // The puzzle's reading is the worst
+        assert_eq!(optimum.worst.mapping, guide.rules_mapping(Interpretation::Outcome).unwrap());
+        assert_eq!(optimum.worst.score, 12);
+        assert_eq!(optimum.worst.expected, 15.);
+    }
+
+    #[test]
+    fn expected_score_against_a_random_opponent() {
+        let guide = parse_input("A X\nA X").unwrap();
+        let mapping = Mapping { meanings: vec![("X".to_string(), Meaning::Outcome(Outcome::Win))] };
+        // Winning always, with paper, scissors or rock
+        let evaluation = guide.evaluate(&mapping).unwrap();
+        assert_eq!((evaluation.score, evaluation.expected), (16, 16.));
+        let mapping = Mapping { meanings: vec![("X".to_string(), Meaning::Move(0))] };
+        let evaluation = guide.evaluate(&mapping).unwrap();
+        assert_eq!((evaluation.score, evaluation.expected), (8, 8.));
+        assert_eq!(guide.evaluate(&Mapping { meanings: vec![] }), None);
+    }
+
+    #[test]
+    fn no_optimum_with_more_symbols_than_meanings() {
+        let rules = Rules::load(concat!(env!("CARGO_MANIFEST_DIR"), "/rules/rpsls.toml")).unwrap();
+        let guide = crate::parse_guide("A V\nA W\nA X\nA Y", rules).unwrap();
+        assert_eq!(guide.optimize(Interpretation::Outcome), None);
+        // Paper and Spock win, then scissors lose for 5 and lizard or rock for 4
+        assert_eq!(guide.optimize(Interpretation::Move).unwrap().best.score, 9 + 8 + 5 + 4);
+    }
+}
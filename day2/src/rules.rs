@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+use aoc_core::ParseError;
+use serde::Deserialize;
+
+use crate::Round;
+
+/// Rules of the puzzle, rock, paper, scissors.
+const STANDARD: &str = include_str!("../rules/rps.toml");
+
+/// Reason why a rule set could not be loaded.
+#[derive(Debug)]
+pub enum RulesError {
+    Io(io::Error),
+    /// The file is not valid TOML or does not describe a valid game.
+    Invalid(String),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RulesError::Io(error) => write!(f, "cannot read the rules: {error}"),
+            RulesError::Invalid(error) => write!(f, "invalid rules: {error}"),
+        }
+    }
+}
+
+impl Error for RulesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RulesError::Io(error) => Some(error),
+            RulesError::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for RulesError {
+    fn from(error: io::Error) -> Self {
+        RulesError::Io(error)
+    }
+}
+
+/// Outcome of a round, for the player giving the response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Lose => write!(f, "lose"),
+            Outcome::Draw => write!(f, "draw"),
+            Outcome::Win => write!(f, "win"),
+        }
+    }
+}
+
+/// How the response column of the strategy guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpretation {
+    /// The response is the move to play.
+    Move,
+    /// The response is the outcome to reach.
+    Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Move {
+    pub name: String,
+    /// Symbol of the move in the opponent column.
+    pub opponent: String,
+    /// Symbol of the move in the response column.
+    pub response: String,
+    /// Points for playing the move.
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct OutcomeRule {
+    /// Symbol of the outcome in the response column.
+    response: String,
+    /// Points for reaching the outcome.
+    score: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct OutcomeRules {
+    lose: OutcomeRule,
+    draw: OutcomeRule,
+    win: OutcomeRule,
+}
+
+/// A cyclic game with any odd number of moves, where each move beats the half
+/// of the other moves listed right before it and loses against the other half.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Rules {
+    moves: Vec<Move>,
+    outcomes: OutcomeRules,
+}
+
+impl Rules {
+    /// Rock, paper, scissors, as played in the puzzle.
+    pub fn standard() -> Self {
+        Rules::from_toml(STANDARD).unwrap()
+    }
+
+    /// Reads the rules from the TOML file at `path`, like the ones of `rules/`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RulesError> {
+        Rules::from_toml(&fs::read_to_string(path)?)
+    }
+
+    pub fn from_toml(rules: &str) -> Result<Self, RulesError> {
+        let rules: Rules = toml::from_str(rules).map_err(|error| RulesError::Invalid(error.to_string()))?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    fn validate(&self) -> Result<(), RulesError> {
+        if self.moves.len() < 3 || self.moves.len().is_multiple_of(2) {
+            return Err(RulesError::Invalid(format!(
+                "there should be an odd number of moves, at least 3, so that each beats as many as it loses \
+                against, not {}", self.moves.len())));
+        }
+
+        let unique = |symbols: Vec<&str>, column: &str| {
+            let mut seen = HashSet::new();
+            match symbols.into_iter().find(|&symbol| !seen.insert(symbol)) {
+                Some(symbol) => Err(RulesError::Invalid(format!("'{symbol}' means two things in the {column} column"))),
+                None => Ok(()),
+            }
+        };
+        unique(self.moves.iter().map(|m| m.opponent.as_str()).collect(), "opponent")?;
+        unique(self.moves.iter().map(|m| m.response.as_str()).collect(), "response")?;
+        unique(Outcome::ALL.iter().map(|&outcome| self.outcome_rule(outcome).response.as_str()).collect(), "response")
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    fn outcome_rule(&self, outcome: Outcome) -> &OutcomeRule {
+        match outcome {
+            Outcome::Lose => &self.outcomes.lose,
+            Outcome::Draw => &self.outcomes.draw,
+            Outcome::Win => &self.outcomes.win,
+        }
+    }
+
+    /// Points for reaching `outcome`.
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_rule(outcome).score
+    }
+
+    /// Move with the symbol `symbol` in the opponent column.
+    pub fn opponent_move(&self, symbol: &str) -> Option<usize> {
+        self.moves.iter().position(|m| m.opponent == symbol)
+    }
+
+    /// Move with the symbol `symbol` in the response column.
+    pub fn response_move(&self, symbol: &str) -> Option<usize> {
+        self.moves.iter().position(|m| m.response == symbol)
+    }
+
+    /// Outcome with the symbol `symbol` in the response column.
+    pub fn response_outcome(&self, symbol: &str) -> Option<Outcome> {
+        Outcome::ALL.into_iter().find(|&outcome| self.outcome_rule(outcome).response == symbol)
+    }
+
+    /// Outcome of playing `mine` against `theirs`.
+    pub fn outcome(&self, mine: usize, theirs: usize) -> Outcome {
+        let n = self.moves.len();
+        let distance = (mine + n - theirs) % n;
+        if distance == 0 {
+            Outcome::Draw
+        } else if distance <= n / 2 {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// Points for playing `mine` against `theirs`.
+    pub fn round_score(&self, mine: usize, theirs: usize) -> u32 {
+        self.moves[mine].score + self.outcome_score(self.outcome(mine, theirs))
+    }
+
+    /// Move to play against `theirs` to reach `outcome`. When several moves
+    /// do, the one worth the most is played, or the first listed of them.
+    pub fn move_for(&self, theirs: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+            .filter(|&mine| self.outcome(mine, theirs) == outcome)
+            .max_by_key(|&mine| (self.moves[mine].score, std::cmp::Reverse(mine)))
+            .unwrap()
+    }
+
+    /// Move played in `round` when its response is read as `interpretation`,
+    /// or `None` when the response means nothing that way.
+    pub fn response(&self, round: &Round, interpretation: Interpretation) -> Option<usize> {
+        match interpretation {
+            Interpretation::Move => self.response_move(&round.response),
+            Interpretation::Outcome => self.response_outcome(&round.response)
+                .map(|outcome| self.move_for(round.opponent, outcome)),
+        }
+    }
+
+    /// Total score of following the strategy guide `rounds`, reading the
+    /// responses as `interpretation`. Fails on the first response that means
+    /// nothing that way.
+    pub fn score(&self, rounds: &[Round], interpretation: Interpretation) -> Result<u32, ParseError> {
+        rounds.iter()
+            .map(|round| match self.response(round, interpretation) {
+                Some(mine) => Ok(self.round_score(mine, round.opponent)),
+                None => Err(ParseError::UnexpectedToken {
+                    line: round.line,
+                    column: round.column,
+                    token: round.response.clone(),
+                }),
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_guide;
+
+    fn rpsls() -> Rules {
+        Rules::load(concat!(env!("CARGO_MANIFEST_DIR"), "/rules/rpsls.toml")).unwrap()
+    }
+
+    #[test]
+    fn each_move_beats_the_half_listed_before_it() {
+        let rules = rpsls();
+        let [rock, spock, paper, lizard, scissors] = [0, 1, 2, 3, 4];
+        assert_eq!(rules.moves()[spock].name, "Spock");
+        for (mine, theirs) in [(spock, rock), (spock, scissors), (paper, spock), (lizard, paper), (rock, lizard)] {
+            assert_eq!(rules.outcome(mine, theirs), Outcome::Win);
+            assert_eq!(rules.outcome(theirs, mine), Outcome::Lose);
+        }
+        assert_eq!(rules.outcome(lizard, lizard), Outcome::Draw);
+    }
+
+    #[test]
+    fn plays_the_move_worth_the_most_for_an_outcome() {
+        let rules = rpsls();
+        // Spock and paper beat rock, lizard and scissors lose against it
+        assert_eq!(rules.moves()[rules.move_for(0, Outcome::Win)].name, "paper");
+        assert_eq!(rules.moves()[rules.move_for(0, Outcome::Lose)].name, "scissors");
+        assert_eq!(rules.move_for(0, Outcome::Draw), 0);
+    }
+
+    #[test]
+    fn scores_a_guide_with_other_rules() {
+        let guide = parse_guide("A Y\nB X\nC Z", rpsls()).unwrap();
+        assert_eq!(guide.score(Interpretation::Move).unwrap(), 4 + 9 + 11);
+        assert_eq!(guide.score(Interpretation::Outcome).unwrap(), 4 + 5 + 11);
+    }
+
+    #[test]
+    fn responses_meaning_nothing_fail_to_score() {
+        // V is lizard's symbol but no outcome's
+        let guide = parse_guide("A Y\nB V", rpsls()).unwrap();
+        assert!(guide.score(Interpretation::Move).is_ok());
+        assert!(matches!(guide.score(Interpretation::Outcome),
+            Err(ParseError::UnexpectedToken { line: 2, column: 3, .. })));
+        assert!(parse_guide("A Q", rpsls()).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let standard = STANDARD.to_string();
+        let four_moves = standard.clone() + "[[moves]]\nname = \"well\"\nopponent = \"D\"\nresponse = \"W\"\nscore = 4\n";
+        assert!(matches!(Rules::from_toml(&four_moves), Err(RulesError::Invalid(error)) if error.contains("odd number")));
+        let repeated = standard.replace("opponent = \"B\"", "opponent = \"A\"");
+        assert!(matches!(Rules::from_toml(&repeated), Err(RulesError::Invalid(error)) if error.contains("'A'")));
+        assert!(matches!(Rules::from_toml("moves = 3"), Err(RulesError::Invalid(_))));
+        assert!(matches!(Rules::load("missing.toml"), Err(RulesError::Io(_))));
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_core::{lines, ParseError, Solution};
+
+
+const DECRYPTION_KEY: i64 = 811589153;
+const N_MIXINGS:usize = 10;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> i64 {
+        solution_v1(numbers)
+    }
+
+    fn part2(numbers: &Self::Input) -> i64 {
+        solution_v2(numbers)
+    }
+}
+
+#[derive(Debug)]
+struct Value {
+    value: i64,
+    idx: usize
+}
+
+/// Reads the encrypted file, a number per line. Fails when there are fewer
+/// than 2 numbers to mix, or no 0 to find the grove coordinates from.
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = lines(input)
+        .map(|line| line.parse::<i64>(line.text))
+        .collect::<Result<_, _>>()?;
+    if numbers.len() < 2 {
+        return Err(ParseError::MissingField { line: numbers.len() + 1, column: 1, field: "number" });
+    }
+    if !numbers.contains(&0) {
+        return Err(ParseError::Invalid { line: 1, reason: "no 0 in the file".to_string() });
+    }
+    Ok(numbers)
+}
+
+fn apply_factor(numbers: &[i64], factor:i64) -> Vec<Value> {
+    numbers
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| Value{ value: value*factor, idx})
+        .collect()
+}
+
+fn mix_number_list(number_list: &mut Vec<Value>, times: usize) {
+    let list_len = number_list.len();
+
+    let mut current_pos;
+    let mut new_position;
+    let mut number;
+    for _ in 0..times {
+        for idx in 0..list_len {
+            current_pos = number_list.iter().position(|x| x.idx == idx).unwrap();
+            number = number_list[current_pos].value;
+            new_position = (current_pos as i64 + number).rem_euclid((list_len-1) as i64) as usize;
+            number_list.remove(current_pos);
+            number_list.insert(new_position, Value { value: number, idx});
+        }
+    }
+}
+
+pub fn solution_v1(numbers: &[i64]) -> i64 {
+    let mut number_list = apply_factor(numbers, 1);
+    let list_len = number_list.len();
+
+    mix_number_list(&mut number_list, 1);
+
+    let zero_index = number_list.iter().position(|x| x.value == 0).unwrap();
+
+    let mut sum = 0;
+    for i in [1000,2000,3000] {
+        sum += &number_list[(zero_index + i) % list_len].value
+    }
+    sum
+}
+
+pub fn solution_v2(numbers: &[i64]) -> i64 {
+    let mut number_list = apply_factor(numbers, DECRYPTION_KEY);
+    let list_len = number_list.len();
+
+    mix_number_list(&mut number_list, N_MIXINGS);
+
+    let zero_index = number_list.iter().position(|x| x.value == 0).unwrap();
+
+    let mut sum = 0;
+    for i in [1000,2000,3000] {
+        sum += &number_list[(zero_index + i) % list_len].value
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_lists_that_cannot_be_mixed() {
+        assert!(matches!(parse_input(""), Err(ParseError::MissingField { line: 1, field: "number", .. })));
+        assert!(matches!(parse_input("0"), Err(ParseError::MissingField { line: 2, field: "number", .. })));
+        assert!(matches!(parse_input("5\n3"), Err(ParseError::Invalid { line: 1, .. })));
+    }
+
+    #[test]
+    fn solves_the_example() {
+        let numbers = parse_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(solution_v1(&numbers), 3);
+        assert_eq!(solution_v2(&numbers), 1623178306);
+    }
+}
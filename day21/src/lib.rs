@@ -0,0 +1,192 @@
+use aoc_core::{lines, ParseError, Solution};
+use std::collections::{HashMap};
+use regex::Regex;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Monkey>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkey_map: &Self::Input) -> i64 {
+        solution_v1(monkey_map)
+    }
+
+    fn part2(monkey_map: &Self::Input) -> i64 {
+        solution_v2(monkey_map)
+    }
+}
+
+#[derive(Clone)]
+pub enum Monkey {
+    MonkeyOperation(MonkeyOperation),
+    MonkeyNumber(i64)
+}
+
+#[derive(Clone)]
+pub struct MonkeyOperation {
+    m1: String,
+    m2: String,
+    operation: char,
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add,
+    SubstractX,
+    XSubstract,
+    Multiply,
+    IsDivided,
+    DivideBy
+}
+
+#[derive(Debug)]
+enum OperationResult {
+    Number(i64),
+    Operations(Vec<(i64, Operation)>)
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    let mut monkey_map = HashMap::new();
+    let mut operations = vec![];
+
+    let re = Regex::new(r"[a-zA-Z0-9+*\-/]+").unwrap();
+    let mut monkey_action: Vec<&str>;
+    for line in lines(input) {
+        monkey_action = re
+        .find_iter(line.text).map(|number| number.as_str())
+        .collect();
+        match monkey_action.len() {
+            0 => return Err(line.missing("monkey name")),
+            1 => return Err(line.missing("monkey job")),
+            2 => {
+                monkey_map.insert(monkey_action[0].to_string(), Monkey::MonkeyNumber(line.parse(monkey_action[1])?));
+            }
+            3 => return Err(line.missing("operand")),
+            4 => {
+                let operation = monkey_action[2];
+                if !["+", "-", "*", "/"].contains(&operation) {return Err(line.unexpected(operation));}
+                monkey_map.insert(monkey_action[0].to_string(), Monkey::MonkeyOperation(MonkeyOperation {
+                    m1: monkey_action[1].to_string(),
+                    m2: monkey_action[3].to_string(),
+                    operation: operation.chars().next().unwrap(),
+                }));
+                operations.push((line, [monkey_action[1], monkey_action[3]]));
+            }
+            _ => return Err(line.unexpected(monkey_action[4])),
+        }
+    }
+
+    // Every operation should wait for monkeys that exist
+    for (line, operands) in operations {
+        for operand in operands {
+            if !monkey_map.contains_key(operand) {return Err(line.unexpected(operand));}
+        }
+    }
+    if !monkey_map.contains_key("root") {
+        return Err(ParseError::MissingField { line: monkey_map.len() + 1, column: 1, field: "monkey root" });
+    }
+
+    Ok(monkey_map)
+}
+
+fn calculate_result(monkey_map: &HashMap<String, Monkey>, root: &str) -> i64 {
+    let monkey = monkey_map.get(root).unwrap().clone();
+    match monkey {
+        Monkey::MonkeyNumber(number) => number,
+        Monkey::MonkeyOperation(monkey_operation) => {
+
+            let m1 = calculate_result(monkey_map, &monkey_operation.m1);
+            let m2 = calculate_result(monkey_map, &monkey_operation.m2);
+            
+            let result = match monkey_operation.operation {
+                '+' => m1 + m2,
+                '-' => m1 - m2,
+                '/' => m1 / m2,
+                '*' => m1 * m2,
+                _ => panic!("should be * - / +")
+            };
+            result
+        }
+    }
+}
+
+pub fn solution_v1(monkey_map: &HashMap<String, Monkey>) -> i64 {
+    calculate_result(monkey_map, "root")
+}
+
+fn calculate_result_v2(monkey_map: &HashMap<String, Monkey>, root: &str) -> OperationResult {
+    if root == "humn" {
+        return OperationResult::Operations(vec![]);
+    }
+    let monkey = monkey_map.get(root).unwrap().clone();
+    match monkey {
+        Monkey::MonkeyNumber(number) => OperationResult::Number(number),
+        Monkey::MonkeyOperation(monkey_operation) => {
+
+            let mut monkey1 = calculate_result_v2(monkey_map, &monkey_operation.m1);
+            let mut monkey2 = calculate_result_v2(monkey_map, &monkey_operation.m2);
+            
+            if let (OperationResult::Number(m1), OperationResult::Number(m2)) = (&monkey1, &monkey2) {
+                let result = match monkey_operation.operation {
+                    '+' => *m1 + *m2,
+                    '-' => *m1 - *m2,
+                    '/' => *m1 / *m2,
+                    '*' => *m1 * *m2,
+                    _ => panic!("should be * - / +")
+                };
+                return OperationResult::Number(result);
+            }
+
+            if let (OperationResult::Operations(m1), OperationResult::Number(m2)) = (&mut monkey1, &monkey2) {
+                match monkey_operation.operation {
+                    '+' => m1.push((*m2, Operation::Add)),
+                    '-' => m1.push((*m2, Operation::SubstractX)),
+                    '/' => m1.push((*m2, Operation::DivideBy)),
+                    '*' => m1.push((*m2, Operation::Multiply)),
+                    _ => panic!("should be * - / +")
+                };
+                return monkey1;
+            }
+
+            if let (OperationResult::Number(m1), OperationResult::Operations(m2)) = (&monkey1, &mut monkey2) {
+                match monkey_operation.operation {
+                    '+' => m2.push((*m1, Operation::Add)),
+                    '-' => m2.push((*m1, Operation::XSubstract)),
+                    '/' => m2.push((*m1, Operation::IsDivided)),
+                    '*' => m2.push((*m1, Operation::Multiply)),
+                    _ => panic!("should be * - / +")
+                };
+                return monkey2;
+            }
+
+            panic!("Should have enter in an if statement");
+        }
+    }
+}
+
+pub fn solution_v2(monkey_map: &HashMap<String, Monkey>) -> i64 {
+    let a = calculate_result_v2(monkey_map, "root");
+    match a {
+        OperationResult::Number(number) => number,
+        OperationResult::Operations(mut operations) => {
+            let mut equals_to = operations.pop().unwrap().0;
+            while let Some((n,op)) = operations.pop() {
+                match op {
+                    Operation::Add => {equals_to -= n}
+                    Operation::SubstractX => {equals_to += n}
+                    Operation::XSubstract => {equals_to = n - equals_to}
+                    Operation::Multiply => {equals_to /= n}
+                    Operation::DivideBy => {equals_to *= n}
+                    Operation::IsDivided => {equals_to = n / equals_to}
+                }
+            }
+            equals_to
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use aoc_core::{lines, Grid, Line, Offset, ParseError, Pos, Solution, Unsolved};
+use regex::Regex;
+
+const STARTING_DIRECTION: Direction = Direction::Right;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Grid<GridObject>, Vec<Action>);
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((grid, actions): &Self::Input) -> usize {
+        solution_v1(grid, actions)
+    }
+
+    fn part2(_: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridObject {
+    Wall,
+    Air,
+    Nothing
+}
+
+pub enum Action {
+    Movement(u32),
+    Turn(char)
+}
+
+#[repr(u8)] #[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+impl From<u8> for Direction {
+    fn from(orig: u8) -> Self {
+        match orig {
+            0 => Direction::Right,
+            1 => Direction::Down,
+            2 => Direction::Left,
+            3 => Direction::Up,
+            _ => panic!("should be 0, 1, 2 or 3")
+        }
+    }
+}
+
+struct GridBounds {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    upper: Vec<usize>,
+    bottom: Vec<usize>,
+}
+
+pub fn parse_input(input: &str) -> Result<(Grid<GridObject>, Vec<Action>), ParseError> {
+    let re = Regex::new(r"([0-9]+)|([LR]+)").unwrap();
+
+    // Get the grid and the directions as separate objects
+    let mut grid: Vec<Line> = lines(input).collect();
+    let missing = |line, field| ParseError::MissingField { line, column: 1, field };
+    let directions = grid.pop().ok_or_else(|| missing(1, "map"))?;
+    // Remove the empty line
+    match grid.pop() {
+        Some(line) if line.text.is_empty() => (),
+        Some(line) => return Err(line.unexpected_at(1, line.text)),
+        None => return Err(missing(1, "map")),
+    }
+
+    // Parse grid
+    let mut parsed_grid: Vec<Vec<GridObject>> = vec![];
+    for line in grid {
+        let mut row = vec![];
+        for (column, ch) in (1..).zip(line.text.chars()) {
+            row.push(match ch {
+                '#' => GridObject::Wall,
+                '.' => GridObject::Air,
+                ' ' => GridObject::Nothing,
+                _ => return Err(line.unexpected_at(column, ch)),
+            });
+        }
+        parsed_grid.push(row);
+    }
+    // The lines stop after the last tile, so the shorter ones are padded
+    let width = parsed_grid.iter().map(Vec::len).max().unwrap_or(0);
+    let on_map = |tile: &GridObject| !matches!(tile, GridObject::Nothing);
+    let parsed_grid = Grid::from_fn(width, parsed_grid.len(), |pos| {
+        parsed_grid[pos.row].get(pos.column).copied().unwrap_or(GridObject::Nothing)
+    });
+    // Moves wrap around to the first and last tiles of each row and column
+    if !parsed_grid.iter().any(on_map) {
+        return Err(missing(1, "map"));
+    }
+    if let Some(row) = parsed_grid.rows().position(|row| !row.iter().any(on_map)) {
+        return Err(ParseError::Invalid { line: row + 1, reason: "no tile on this row of the map".to_string() });
+    }
+    if let Some(column) = (0..width).find(|&column| !parsed_grid.column(column).any(on_map)) {
+        return Err(ParseError::Invalid { line: 1, reason: format!("no tile in column {} of the map", column + 1) });
+    }
+
+    // Parse directions
+    fn parse_direction(line: &Line, dir_str: &str) -> Result<Action, ParseError> {
+        if dir_str == "L" || dir_str == "R" {
+            return Ok(Action::Turn(dir_str.chars().next().unwrap()));
+        }
+        Ok(Action::Movement(line.parse(dir_str)?))
+    }
+
+    let mut actions = vec![];
+    let mut last_end = 0;
+    for token in re.find_iter(directions.text) {
+        if token.start() != last_end {
+            return Err(directions.unexpected(&directions.text[last_end..token.start()]));
+        }
+        actions.push(parse_direction(&directions, token.as_str())?);
+        last_end = token.end();
+    }
+    if last_end != directions.text.len() {
+        return Err(directions.unexpected(&directions.text[last_end..]));
+    }
+
+    Ok((parsed_grid, actions))
+}
+
+fn get_bounds(grid: &Grid<GridObject>) -> GridBounds {
+    let on_map = |tile: &GridObject| !matches!(tile, GridObject::Nothing);
+
+    // Left and right bounds of each row
+    let left_bounds: Vec<usize> = grid.rows()
+        .map(|row| row.iter().position(on_map).unwrap())
+        .collect();
+    let right_bounds: Vec<usize> = grid.rows()
+        .map(|row| row.iter().rposition(on_map).unwrap() + 1)
+        .collect();
+
+    // Upper and bottom bounds of each column
+    let upper_bounds: Vec<usize> = (0..grid.width())
+        .map(|column| grid.column(column).position(on_map).unwrap())
+        .collect();
+    let bottom_bounds: Vec<usize> = (0..grid.width())
+        .map(|column| grid.column(column).rposition(on_map).unwrap() + 1)
+        .collect();
+
+    GridBounds {left: left_bounds, right: right_bounds, upper: upper_bounds, bottom: bottom_bounds}
+
+}
+
+impl Direction {
+    fn offset(self) -> Offset {
+        match self {
+            Direction::Right => Offset::RIGHT,
+            Direction::Down => Offset::DOWN,
+            Direction::Left => Offset::LEFT,
+            Direction::Up => Offset::UP,
+        }
+    }
+}
+
+/// Next position moving towards `current_direction`, wrapping around to the
+/// other side of the map, or `None` when a wall is in the way.
+fn get_new_position(
+    grid: &Grid<GridObject>,
+    bounds: &GridBounds,
+    current_position: Pos,
+    current_direction: Direction)
+-> Option<Pos> {
+
+    let Pos { row, column } = current_position;
+    let new_position = grid.step(current_position, current_direction.offset())
+        .filter(|&next| !matches!(grid[next], GridObject::Nothing))
+        .unwrap_or(match current_direction {
+            Direction::Down => Pos::new(bounds.upper[column], column),
+            Direction::Up => Pos::new(bounds.bottom[column] - 1, column),
+            Direction::Right => Pos::new(row, bounds.left[row]),
+            Direction::Left => Pos::new(row, bounds.right[row] - 1),
+        });
+
+    if let GridObject::Wall = grid[new_position] {
+        return None;
+    }
+    Some(new_position)
+
+}
+
+fn final_position(grid: &Grid<GridObject>, actions: &[Action], bounds: &GridBounds) -> (Pos, Direction) {
+
+    let starting_point = Pos::new(0, bounds.left[0]);
+    let mut current_position = starting_point;
+    let mut current_direction = STARTING_DIRECTION;
+
+    for action in actions {
+        match action {
+            Action::Movement(n) => {
+                for _ in 0..*n {
+                    if let Some(new_pos) = get_new_position(grid, bounds, current_position, current_direction) {
+                        current_position = new_pos;
+                    }
+                    else {
+                        break;
+                    }
+                }
+            }
+            Action::Turn(ch) => {
+                if *ch == 'R' {
+                    current_direction = Direction::from((current_direction as u8 + 1).rem_euclid(4));
+                }
+                else {
+                    current_direction = Direction::from(((current_direction as i8 - 1) as u8).rem_euclid(4));
+                }
+            }
+        }
+    }
+
+    (current_position, current_direction)
+}
+
+pub fn solution_v1(grid: &Grid<GridObject>, actions: &[Action]) -> usize {
+    let bounds = get_bounds(grid);
+
+    let (final_position, final_direction) = final_position(grid, actions, &bounds);
+
+    (1000 * (final_position.row+1)) + (4 * (final_position.column+1)) + (final_direction as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_maps_without_tiles() {
+        assert!(matches!(parse_input("\n10R"), Err(ParseError::MissingField { line: 1, field: "map", .. })));
+        assert!(matches!(parse_input("  \n\n10R"), Err(ParseError::MissingField { line: 1, field: "map", .. })));
+        assert!(matches!(parse_input("  \n..\n\n1R"), Err(ParseError::Invalid { line: 1, .. })));
+        assert!(matches!(parse_input("..\n  \n..\n\n1R"), Err(ParseError::Invalid { line: 2, .. })));
+        assert!(matches!(parse_input(". .\n. .\n\n1R"), Err(ParseError::Invalid { line: 1, reason }) if reason == "no tile in column 2 of the map"));
+    }
+
+    #[test]
+    fn solves_the_example() {
+        let (grid, actions) = parse_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(solution_v1(&grid, &actions), 6032);
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use aoc_core::{Grid, Offset, ParseError, Pos, Solution};
+
+/// The direction an elf considers, followed by the two next to it, in the order
+/// the elves consider them in the first round: north, south, west and east.
+const DIRECTIONS: [[Offset; 3]; 4] = [
+    [Offset::UP, Offset::UP_RIGHT, Offset::UP_LEFT],
+    [Offset::DOWN, Offset::DOWN_RIGHT, Offset::DOWN_LEFT],
+    [Offset::LEFT, Offset::DOWN_LEFT, Offset::UP_LEFT],
+    [Offset::RIGHT, Offset::DOWN_RIGHT, Offset::UP_RIGHT],
+];
+const ITER_V1:usize = 10;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(elves_positions: &Self::Input) -> usize {
+        solution_v1(elves_positions.clone())
+    }
+
+    fn part2(elves_positions: &Self::Input) -> usize {
+        solution_v2(elves_positions.clone())
+    }
+}
+
+/// Reads the grove, where each cell tells whether an elf stands there. Fails
+/// when there is no elf, as the grove would have no bounds.
+pub fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    let elves_positions = Grid::parse(input, |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if !elves_positions.iter().any(|&elf| elf) {
+        return Err(ParseError::Invalid { line: 1, reason: "no elf in the grove".to_string() });
+    }
+    Ok(elves_positions)
+}
+
+fn is_elf_alone(elves_positions: &Grid<bool>, elf_pos: Pos) -> bool {
+    elves_positions.neighbours8(elf_pos).all(|pos| !elves_positions[pos])
+}
+
+/// Moves the elves for a round where they consider `DIRECTIONS` from
+/// `current_dir` on. Returns whether any elf moved.
+fn play_round(elves_positions: &mut Grid<bool>, current_dir: usize) -> bool {
+    // Elves can only move away from the border if the grid grows first, by
+    // a row or a column on each side an elf touches
+    let (last_row, last_column) = (elves_positions.height() - 1, elves_positions.width() - 1);
+    let mut sides = [0; 4];
+    for (pos, _) in elves_positions.enumerate().filter(|&(_, &elf)| elf) {
+        for (side, touched) in sides.iter_mut()
+            .zip([pos.row == 0, pos.row == last_row, pos.column == 0, pos.column == last_column]) {
+            *side |= touched as usize;
+        }
+    }
+    if sides != [0; 4] {
+        *elves_positions = elves_positions.extended(sides, false);
+    }
+
+    let mut elves_movements = HashMap::new();
+    for (pos, _) in elves_positions.enumerate().filter(|&(_, &elf)| elf) {
+        if is_elf_alone(elves_positions, pos) {
+            continue;
+        }
+        'directions: for dir_idx in 0..4 {
+            let directions = DIRECTIONS[(dir_idx + current_dir) % 4];
+
+            // Check if the three spots are free, if not, check the next direction
+            for direction in directions {
+                if elves_positions.step(pos, direction).is_some_and(|next| elves_positions[next]) {
+                    continue 'directions;
+                }
+            }
+
+            // If there is already one elf wanting to go there, none will move
+            let new_pos = pos.offset(directions[0]).unwrap();
+            match elves_movements.entry(new_pos) {
+                Entry::Occupied(mut e) => {
+                    e.insert(None);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(Some(pos));
+                }
+            }
+
+            break 'directions;
+        }
+    }
+
+    let mut moved = false;
+    for (new_pos, prev_pos) in elves_movements {
+        if let Some(prev_pos) = prev_pos {
+            elves_positions[prev_pos] = false;
+            elves_positions[new_pos] = true;
+            moved = true;
+        }
+    }
+    moved
+}
+
+pub fn solution_v1(mut elves_positions: Grid<bool>) -> usize {
+    for round in 0..ITER_V1 {
+        play_round(&mut elves_positions, round % 4);
+    }
+
+    let elves: Vec<Pos> = elves_positions.enumerate().filter(|&(_, &elf)| elf).map(|(pos, _)| pos).collect();
+    let min_x = elves.iter().map(|pos| pos.row).min().unwrap();
+    let max_x = elves.iter().map(|pos| pos.row).max().unwrap();
+    let min_y = elves.iter().map(|pos| pos.column).min().unwrap();
+    let max_y = elves.iter().map(|pos| pos.column).max().unwrap();
+
+    (1+max_x-min_x) * (1+max_y-min_y) - elves.len()
+}
+
+pub fn solution_v2(mut elves_positions: Grid<bool>) -> usize {
+    let mut iterations = 1;
+    while play_round(&mut elves_positions, (iterations - 1) % 4) {
+        iterations += 1;
+    }
+
+    iterations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_grove_without_elves() {
+        assert!(matches!(parse_input("...\n..."), Err(ParseError::Invalid { line: 1, .. })));
+        assert!(matches!(parse_input(""), Err(ParseError::Invalid { line: 1, .. })));
+    }
+
+    #[test]
+    fn grows_on_the_sides_the_elves_reach() {
+        // The elves touch the top, the left and the right, then move north
+        let mut elves_positions = parse_input("##\n..").unwrap();
+        assert!(play_round(&mut elves_positions, 0));
+        assert_eq!(elves_positions.render(|&elf| if elf {'#'} else {'.'}).to_string(), ".##.\n....\n....");
+        // Then they only touch the top, and move south
+        assert!(play_round(&mut elves_positions, 1));
+        assert_eq!(elves_positions.render(|&elf| if elf {'#'} else {'.'}).to_string(), "....\n....\n.##.\n....");
+    }
+
+    #[test]
+    fn solves_the_example() {
+        let elves_positions = parse_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(solution_v1(elves_positions.clone()), 110);
+        assert_eq!(solution_v2(elves_positions), 20);
+    }
+
+    #[test]
+    fn solves_a_single_elf() {
+        let elves_positions = parse_input("..\n.#").unwrap();
+        assert_eq!(solution_v1(elves_positions.clone()), 0);
+        assert_eq!(solution_v2(elves_positions), 1);
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::{HashSet, VecDeque};
+use aoc_core::{lines, Grid, ParseError, Pos, Solution};
+use std::vec;
+
+/// The entrance of the valley, above its first cell.
+const INITIAL_POSITION: Pos = Pos::new(usize::MAX, usize::MAX);
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Grid<Vec<Blizzard>>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> isize {
+        solution_v1(grid.clone())
+    }
+
+    fn part2(grid: &Self::Input) -> isize {
+        solution_v2(grid.clone())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Blizzard {
+    Upwards,
+    Downwards,
+    Rightwards,
+    Leftwards
+}
+
+fn char_into_blizzard(ch: char) -> Option<Vec<Blizzard>> {
+    match ch {
+        '.' => Some(vec![]),
+        '<' => Some(vec![Blizzard::Leftwards]),
+        '>' => Some(vec![Blizzard::Rightwards]),
+        '^' => Some(vec![Blizzard::Upwards]),
+        'v' => Some(vec![Blizzard::Downwards]),
+        _ => None
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Vec<Blizzard>>, ParseError> {
+    let mut lines = lines(input);
+
+    lines.next(); // Remove first line
+    let mut grid: Vec<Vec<Vec<Blizzard>>> = vec![];
+    for line in lines {
+        let mut row = vec![];
+        for (column, ch) in (1..).zip(line.text.chars()) {
+            match char_into_blizzard(ch) {
+                Some(blizzards) => row.push(blizzards),
+                None if ch == '#' => (),
+                None => return Err(line.unexpected_at(column, ch)),
+            }
+        }
+        grid.push(row);
+    }
+    grid.pop();
+
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(ParseError::MissingField { line: 2, column: 1, field: "valley" });
+    }
+    Grid::from_rows(grid).ok_or_else(|| ParseError::Invalid { line: 2, reason: "the valley is not a rectangle".to_string() })
+}
+
+fn blizzard_new_position(grid: &Grid<Vec<Blizzard>>, current_pos: Pos, blizzard: &Blizzard) -> Pos {
+    let (height, width) = (grid.height(), grid.width());
+    let Pos { row, column } = current_pos;
+
+    // Blizzards leaving the valley come back on the other side
+    match blizzard {
+        Blizzard::Downwards => Pos::new((row + 1) % height, column),
+        Blizzard::Rightwards => Pos::new(row, (column + 1) % width),
+        Blizzard::Upwards => Pos::new((row + height - 1) % height, column),
+        Blizzard::Leftwards => Pos::new(row, (column + width - 1) % width),
+    }
+}
+
+/// Where the elf at `elf_position` can be after a minute, or stay. The exit of
+/// the valley is `exit`, right below its last cell.
+fn get_possible_elf_movements(grid: &Grid<Vec<Blizzard>>, elf_position: Pos, exit: Pos) -> Vec<Pos> {
+    let first = Pos::new(0, 0);
+    let last = Pos::new(grid.height() - 1, grid.width() - 1);
+
+    if elf_position == INITIAL_POSITION {
+        if grid[first].is_empty() {
+            return vec![first, INITIAL_POSITION];
+        }
+        return vec![INITIAL_POSITION];
+    }
+    else if elf_position == exit {
+        if grid[last].is_empty() {
+            return vec![last, elf_position];
+        }
+        return vec![elf_position];
+    }
+
+    // North, south, left, right, or the same position
+    grid.neighbours(elf_position)
+        .chain([elf_position])
+        .filter(|&pos| grid[pos].is_empty())
+        .collect()
+}
+
+fn lowest_common_multple(n1: usize, n2: usize) -> usize {
+    let mut x;
+    let mut y;
+    if n1 > n2 {
+        x = n1;
+        y = n2;
+    }
+    else {
+        x = n2;
+        y = n1;
+    }
+
+    let mut rem = x % y;
+
+    while rem != 0 {
+        x = y;
+        y = rem;
+        rem = x % y;
+    }
+
+    (n1 * n2) / y
+}
+
+fn get_quickest_path(grid: &mut Grid<Vec<Blizzard>>,
+    initial_pos: Pos,
+    final_pos: Pos) -> isize {
+
+    let exit = Pos::new(grid.height(), grid.width());
+    let lcm = lowest_common_multple(grid.height(), grid.width());
+    let initial_time: usize = 0;
+    let mut grid_time = 0;
+
+    let mut blizzards_new_pos = vec![];
+    let mut position_queue = VecDeque::new();
+    let mut visited_cells_map = HashSet::new();
+
+    position_queue.push_front((initial_pos, initial_time));
+
+    while let Some((elf_position, time)) = position_queue.pop_front()  {
+
+
+        // Check if we are in the final step
+        if elf_position == final_pos {
+            return (time+1) as isize;
+        }
+
+        // If that cell was visited on a spot in which the map is the same (lcm multiple), we skip it
+        if (lcm < time && visited_cells_map.contains(&(elf_position, time-lcm))) 
+            || visited_cells_map.contains(&(elf_position, time)) {
+
+            visited_cells_map.insert((elf_position, time));
+            continue;
+        }
+
+
+        if grid_time <= time {
+
+            // We empty the grid and fill the vector 'blizzards_new_pos' with the new positions of the blizzards
+            for pos in grid.positions() {
+                for blizzard in std::mem::take(&mut grid[pos]) {
+                    blizzards_new_pos.push((blizzard, blizzard_new_position(grid, pos, &blizzard)));
+                }
+            }
+
+            // We empty the vector 'blizzards_new_pos' and fill the grid with the new positions
+            for (blizard, pos) in blizzards_new_pos.drain(..) {
+                grid[pos].push(blizard);
+            }
+            grid_time += 1;
+
+        }       
+        
+        // We insert the new positions on the queue and the set
+        let possible_elf_movements = get_possible_elf_movements(grid, elf_position, exit);
+
+        position_queue.extend(possible_elf_movements.iter().map(|x| (*x, time+1)));
+        visited_cells_map.insert((elf_position, time));
+    }
+
+    -1
+
+}
+
+pub fn solution_v1(mut grid: Grid<Vec<Blizzard>>) -> isize {
+    let last = Pos::new(grid.height()-1, grid.width()-1);
+
+    get_quickest_path(&mut grid, INITIAL_POSITION, last)
+}
+
+pub fn solution_v2(mut grid: Grid<Vec<Blizzard>>) -> isize {
+    let last = Pos::new(grid.height()-1, grid.width()-1);
+    let exit = Pos::new(grid.height(), grid.width());
+
+    let quickest_path = get_quickest_path(&mut grid, INITIAL_POSITION, last);
+    let quickest_path_2 = get_quickest_path(&mut grid, exit, Pos::new(0, 0));
+    let quickest_path_3 = get_quickest_path(&mut grid, INITIAL_POSITION, last);
+
+    quickest_path+quickest_path_2+quickest_path_3
+}
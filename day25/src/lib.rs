@@ -0,0 +1,116 @@
+use aoc_core::{lines, Line, ParseError, Solution, Unsolved};
+use std::vec;
+
+const BASE:i128 = 5;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<i128>;
+    type Part1 = String;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> String {
+        solution(numbers)
+    }
+
+    fn part2(_: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i128>, ParseError> {
+    lines(input)
+        .map(|line| snafu_to_decimal(&line))
+        .collect()
+}
+
+fn char_to_number(ch: char) -> Option<i128> {
+    match ch {
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None
+    }
+}
+
+fn number_to_char(n: i128) -> (char, bool) {
+    let mut carry = false;
+    let ch: char;
+    match n {
+        0 => ch = '0',
+        1 => ch = '1',
+        2 => ch = '2',
+        3 => {ch = '='; carry = true;},
+        4 => {ch = '-'; carry = true;},
+        5 => {ch = '0'; carry = true;},
+        _ => panic!("should be one of those options")
+    }
+
+    (ch, carry)
+}
+
+fn snafu_to_decimal(line: &Line) -> Result<i128, ParseError> {
+    let n_digits = line.text.chars().count();
+
+    line.text.chars().rev().enumerate()
+    .map(|(x, y)| {
+        let digit = char_to_number(y).ok_or_else(|| line.unexpected_at(n_digits - x, y))?;
+        Ok(BASE.pow(x as u32) * digit)
+    })
+    .sum::<Result<i128, ParseError>>()
+
+}
+
+fn decimal_to_snafu(mut number: i128) -> String {
+
+    let mut str = String::new();
+
+    let mut current_power = 0;
+    loop {
+        if number / BASE.pow(current_power) < 5 {
+            break;
+        } 
+        current_power += 1;
+    }
+    
+    let mut divs = vec![];
+    let mut pow;
+    loop {
+        pow = BASE.pow(current_power);
+
+        divs.push(number / pow);
+        number %= pow;
+
+        if current_power == 0 {break;}
+        current_power -= 1;
+    }
+
+    let mut carry = false;
+    let mut ch;
+    for mut div in divs.into_iter().rev() {
+        if carry {div += 1;}
+        (ch, carry) = number_to_char(div);
+        str.push(ch);
+    }
+
+    if carry {str.push('1')}
+
+    str = str.chars().rev().collect::<String>();
+
+    str
+}
+
+pub fn solution(numbers: &[i128]) -> String {
+    let sum = numbers.iter().sum::<i128>();
+
+    
+
+    decimal_to_snafu(sum)
+}
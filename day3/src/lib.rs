@@ -0,0 +1,290 @@
+use aoc_core::{lines, ParseError, Solution};
+use std::fmt::{self, Display};
+use std::num::NonZeroUsize;
+
+const COMPARTMENTS: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        get_priorities(rucksacks, COMPARTMENTS)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        get_group_priorities(rucksacks, GROUP_SIZE)
+    }
+}
+
+/// Returns the items of each rucksack, failing on the first item that is not
+/// a letter.
+pub fn parse_input(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    lines(input)
+        .map(|line| {
+            Rucksack::new(line.text.as_bytes()).map_err(|idx| {
+                let item = &line.text[idx..];
+                line.unexpected_at(line.column(item), item.chars().take(1).collect::<String>())
+            })
+        })
+        .collect()
+}
+
+/// Priority of `item`: 1 to 26 for `a` to `z`, then 27 to 52 for `A` to `Z`,
+/// or `None` if it is not a letter.
+pub fn priority(item: u8) -> Option<u32> {
+    match item {
+        b'a'..=b'z' => Some((item - b'a') as u32 + 1),
+        b'A'..=b'Z' => Some((item - b'A') as u32 + 27),
+        _ => None,
+    }
+}
+
+/// Item of priority `priority`, from 1 to 52.
+fn item(priority: u8) -> u8 {
+    match priority {
+        1..=26 => b'a' + priority - 1,
+        _ => b'A' + priority - 27,
+    }
+}
+
+/// The items of a rucksack, in order. Every item is a letter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rucksack {
+    /// Priority of each item.
+    priorities: Vec<u8>,
+}
+
+impl Rucksack {
+    /// The rucksack holding `items`, or the index of the first of them that
+    /// is not a letter.
+    pub fn new(items: &[u8]) -> Result<Self, usize> {
+        let priorities = items.iter().enumerate()
+            .map(|(idx, &item)| priority(item).map(|priority| priority as u8).ok_or(idx))
+            .collect::<Result<_, _>>()?;
+        Ok(Rucksack { priorities })
+    }
+
+    pub fn len(&self) -> usize {
+        self.priorities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.priorities.is_empty()
+    }
+
+    /// The items of the rucksack, in order.
+    pub fn items(&self) -> impl Iterator<Item = u8> + '_ {
+        self.priorities.iter().map(|&priority| item(priority))
+    }
+
+    /// The `compartments` compartments of the rucksack, which hold the same
+    /// number of items, give or take one.
+    pub fn compartments(&self, compartments: NonZeroUsize) -> impl Iterator<Item = Rucksack> + '_ {
+        self.compartment_priorities(compartments).map(|priorities| Rucksack { priorities: priorities.to_vec() })
+    }
+
+    /// The priorities of the items of each compartment, borrowed from the
+    /// rucksack.
+    fn compartment_priorities(&self, compartments: NonZeroUsize) -> impl DoubleEndedIterator<Item = &[u8]> + '_ {
+        let (len, compartments) = (self.len(), compartments.get());
+        (0..compartments).map(move |idx| &self.priorities[idx * len / compartments..(idx + 1) * len / compartments])
+    }
+
+    /// Items found in every one of the `compartments` of the rucksack.
+    pub fn compartments_shared(&self, compartments: NonZeroUsize) -> Items {
+        shared(self.compartments(compartments).map(|compartment| Items::of(&compartment)))
+    }
+}
+
+impl Display for Rucksack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.items().try_for_each(|item| write!(f, "{}", item as char))
+    }
+}
+
+/// A set of items, as a mask where bit `n` stands for the item of priority `n + 1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(u64);
+
+impl Items {
+    pub const NONE: Items = Items(0);
+    /// Every letter, from priority 1 to 52.
+    pub const ALL: Items = Items((1 << 52) - 1);
+
+    /// The items of `rucksack`, each counted once.
+    pub fn of(rucksack: &Rucksack) -> Self {
+        Items::of_priorities(&rucksack.priorities)
+    }
+
+    fn of_priorities(priorities: &[u8]) -> Self {
+        Items(priorities.iter().fold(0, |mask, &priority| mask | 1 << (priority - 1)))
+    }
+
+    fn contains_priority(self, priority: u8) -> bool {
+        self.0 & 1 << (priority - 1) != 0
+    }
+
+    pub fn contains(self, item: u8) -> bool {
+        priority(item).is_some_and(|priority| self.contains_priority(priority as u8))
+    }
+
+    /// Items in both `self` and `other`.
+    pub fn intersection(self, other: Items) -> Self {
+        Items(self.0 & other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the items of the set, increasing.
+    fn iter_priorities(self) -> impl Iterator<Item = u8> {
+        (1..=52).filter(move |&priority| self.contains_priority(priority))
+    }
+
+    /// The items of the set, by increasing priority.
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        self.iter_priorities().map(item)
+    }
+
+    /// Sum of the priorities of the items of the set.
+    pub fn priorities(self) -> u32 {
+        self.iter_priorities().map(u32::from).sum()
+    }
+}
+
+impl Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.iter().try_for_each(|item| write!(f, "{}", item as char))
+    }
+}
+
+/// Items in every one of `sets`, or none at all if there is no set.
+pub fn shared(sets: impl IntoIterator<Item = Items>) -> Items {
+    let mut sets = sets.into_iter();
+    match sets.next() {
+        Some(first) => sets.fold(first, Items::intersection),
+        None => Items::NONE,
+    }
+}
+
+/// The first item of the last of `rucksacks` found in every other one, as
+/// the puzzle expects a single shared item. With a single rucksack, that is
+/// its first item.
+pub fn first_shared(rucksacks: &[Rucksack]) -> Option<u8> {
+    first_shared_priority(rucksacks.iter().map(|rucksack| rucksack.priorities.as_slice())).map(item)
+}
+
+/// Priority of the first item of the last of `parts` found in every other
+/// one, as for [`first_shared`].
+fn first_shared_priority<'a>(mut parts: impl DoubleEndedIterator<Item = &'a [u8]>) -> Option<u8> {
+    let last = parts.next_back()?;
+    let others = parts.map(Items::of_priorities).fold(Items::ALL, Items::intersection);
+    last.iter().copied().find(|&priority| others.contains_priority(priority))
+}
+
+/// Items found in every rucksack of each group of `group_size` consecutive
+/// rucksacks. A last group with fewer rucksacks is ignored.
+pub fn groups_shared(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> impl Iterator<Item = Items> + '_ {
+    rucksacks.chunks_exact(group_size.get()).map(|group| shared(group.iter().map(Items::of)))
+}
+
+/// Sum of the priorities of the first item found in every compartment of
+/// each rucksack, when they have `compartments` compartments.
+pub fn get_priorities(rucksacks: &[Rucksack], compartments: NonZeroUsize) -> u32 {
+    rucksacks.iter()
+        .filter_map(|rucksack| first_shared_priority(rucksack.compartment_priorities(compartments)))
+        .map(u32::from)
+        .sum()
+}
+
+/// Sum of the priorities of the first item found in every rucksack of each
+/// group, when the elves are in groups of `group_size`. A last group with
+/// fewer rucksacks is ignored.
+pub fn get_group_priorities(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> u32 {
+    rucksacks.chunks_exact(group_size.get())
+        .filter_map(|group| first_shared_priority(group.iter().map(|rucksack| rucksack.priorities.as_slice())))
+        .map(u32::from)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TWO: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+
+    fn rucksack(items: &str) -> Rucksack {
+        Rucksack::new(items.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn priorities_of_letters_only() {
+        assert_eq!(priority(b'a'), Some(1));
+        assert_eq!(priority(b'z'), Some(26));
+        assert_eq!(priority(b'A'), Some(27));
+        assert_eq!(priority(b'Z'), Some(52));
+        assert_eq!(priority(b'1'), None);
+        assert_eq!(priority(b'['), None);
+    }
+
+    #[test]
+    fn rejects_items_that_are_not_letters() {
+        assert_eq!(Rucksack::new(b"abC-d"), Err(3));
+        assert!(matches!(parse_input("ab\nxé"), Err(ParseError::UnexpectedToken { line: 2, column: 2, token }) if token == "é"));
+    }
+
+    #[test]
+    fn masks() {
+        let items = Items::of(&rucksack("caZac"));
+        assert_eq!(items.len(), 3);
+        assert_eq!(items.to_string(), "acZ");
+        assert_eq!(items.priorities(), 1 + 3 + 52);
+        assert!(items.contains(b'Z') && !items.contains(b'z') && !items.contains(b'-'));
+        assert_eq!(items.intersection(Items::of(&rucksack("ZZb"))).to_string(), "Z");
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::ALL.priorities(), (1..=52).sum());
+        assert_eq!(shared([]), Items::NONE);
+    }
+
+    #[test]
+    fn compartments_hold_as_many_items_give_or_take_one() {
+        let three = NonZeroUsize::new(3).unwrap();
+        let compartments: Vec<String> = rucksack("abcdefgh").compartments(three).map(|c| c.to_string()).collect();
+        assert_eq!(compartments, ["ab", "cde", "fgh"]);
+        assert_eq!(rucksack("aXbXcX").compartments_shared(three).to_string(), "X");
+        assert_eq!(rucksack("aXbXca").compartments_shared(three).to_string(), "");
+        assert_eq!(rucksack("aXbaXa").compartments_shared(TWO).to_string(), "aX");
+    }
+
+    #[test]
+    fn scores_the_first_shared_item_of_the_last_compartment() {
+        // Both a and X are shared, X comes first in the second compartment
+        let rucksacks = [rucksack("aXcbXa")];
+        assert_eq!(first_shared(&rucksacks[0].compartments(TWO).collect::<Vec<_>>()), Some(b'X'));
+        assert_eq!(get_priorities(&rucksacks, TWO), 50);
+        assert_eq!(get_priorities(&[rucksack("abcd")], TWO), 0);
+    }
+
+    #[test]
+    fn groups_ignore_a_last_incomplete_group() {
+        let rucksacks = [rucksack("ab"), rucksack("bc"), rucksack("bd"), rucksack("a")];
+        assert_eq!(groups_shared(&rucksacks, TWO).map(|items| items.to_string()).collect::<Vec<_>>(), ["b", ""]);
+        assert_eq!(get_group_priorities(&rucksacks, TWO), 2);
+        assert_eq!(get_group_priorities(&rucksacks, NonZeroUsize::new(3).unwrap()), 2);
+        assert_eq!(get_group_priorities(&rucksacks, NonZeroUsize::new(5).unwrap()), 0);
+    }
+}
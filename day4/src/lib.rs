@@ -0,0 +1,89 @@
+use aoc_core::{lines, Interval, Line, ParseError, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<[Interval<u32>; 2]>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part1(elf_assignments: &Self::Input) -> u32 {
+        count_containing_pairs(elf_assignments)
+    }
+
+    fn part2(elf_assignments: &Self::Input) -> u32 {
+        count_overlapping_pairs(elf_assignments)
+    }
+}
+
+fn parse_assignment(line: &Line, assignment: Option<&str>) -> Result<Interval<u32>, ParseError> {
+    let assignment = line.field(assignment, "section assignment")?;
+    let mut sections = assignment.split('-');
+    let first = line.parse_field(sections.next(), "first section")?;
+    let last = line.parse_field(sections.next(), "last section")?;
+    if let Some(extra) = sections.next() {return Err(line.unexpected(extra));}
+    // The sections of an assignment cannot go backwards
+    Interval::new(first, last).ok_or_else(|| line.unexpected(assignment))
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<[Interval<u32>; 2]>, ParseError> {
+    let mut vec = Vec::new();
+
+    for line in lines(input) {
+        let mut elves = line.text.split(',');
+        let elf_assignments = [
+            parse_assignment(&line, elves.next())?,
+            parse_assignment(&line, elves.next())?,
+        ];
+        if let Some(extra) = elves.next() {return Err(line.unexpected(extra));}
+        vec.push(elf_assignments);
+    }
+    Ok(vec)
+}
+
+pub fn count_containing_pairs(elf_assignments: &[[Interval<u32>; 2]]) -> u32 {
+    elf_assignments.iter()
+        .filter(|[first, second]| first.contains_interval(second) || second.contains_interval(first))
+        .count() as u32
+}
+
+pub fn count_overlapping_pairs(elf_assignments: &[[Interval<u32>; 2]]) -> u32 {
+    elf_assignments.iter()
+        .filter(|[first, second]| first.overlaps(second))
+        .count() as u32
+}
+
+/// Number of sections, between the first and the last assigned, that no elf
+/// has to clean.
+pub fn count_uncovered_sections(elf_assignments: &[[Interval<u32>; 2]]) -> u32 {
+    let assignments = || elf_assignments.iter().flatten().copied();
+    let Some(within) = assignments().reduce(|all, assignment| all.hull(&assignment)) else {
+        return 0;
+    };
+    Interval::gaps(assignments(), within).iter()
+        .map(Interval::len)
+        .sum()
+}
+
+/// Sections that at least `elves` elves have to clean.
+pub fn sections_covered_by(elf_assignments: &[[Interval<u32>; 2]], elves: usize) -> Vec<Interval<u32>> {
+    Interval::covered_by(elf_assignments.iter().flatten().copied(), elves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uncovered_sections_up_to_the_last_section_number() {
+        let pairs = parse_pairs("4294967295-4294967295,1-2").unwrap();
+        assert_eq!(count_uncovered_sections(&pairs), u32::MAX - 3);
+        assert_eq!(sections_covered_by(&pairs, 1).len(), 2);
+        assert_eq!(count_uncovered_sections(&parse_pairs("2-4,6-8\n3-5,1-1").unwrap()), 0);
+        assert_eq!(count_uncovered_sections(&parse_pairs("2-4,7-8").unwrap()), 2);
+    }
+}
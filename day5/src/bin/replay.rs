@@ -0,0 +1,83 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, DEFAULT_INPUT};
+use clap::Parser;
+use day5::{draw, parse_input, replay, Crane, CrateMover9000, CrateMover9001, LimitedCrateMover, Mode, Move, MoveError};
+
+/// Prints the stacks after every move of the rearrangement procedure.
+#[derive(Parser)]
+struct Args {
+    /// Rearrangement procedure
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+
+    /// Crane doing the moves: `9000`, `9001`, or `limited-N` for a crane
+    /// lifting up to N crates at once
+    #[arg(default_value = "9000", value_parser = parse_model)]
+    crane: Model,
+
+    /// Skips the moves that cannot be done instead of stopping the procedure
+    #[arg(long)]
+    lenient: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Model {
+    Mover9000,
+    Mover9001,
+    Limited(NonZeroUsize),
+}
+
+fn parse_model(model: &str) -> Result<Model, String> {
+    match model {
+        "9000" => Ok(Model::Mover9000),
+        "9001" => Ok(Model::Mover9001),
+        other => other.strip_prefix("limited-")
+            .and_then(|capacity| capacity.parse().ok())
+            .map(Model::Limited)
+            .ok_or_else(|| "expected 9000, 9001 or limited-N with N at least 1".to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+    let mode = if args.lenient {Mode::Lenient} else {Mode::Strict};
+
+    let (mut stacks, moves) = match parse_input(&input) {
+        Ok(procedure) => procedure,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let replayed = match args.crane {
+        Model::Mover9000 => print_replay(&mut stacks, &moves, &CrateMover9000, mode),
+        Model::Mover9001 => print_replay(&mut stacks, &moves, &CrateMover9001, mode),
+        Model::Limited(capacity) => print_replay(&mut stacks, &moves, &LimitedCrateMover { capacity }, mode),
+    };
+
+    match replayed {
+        Ok(skipped) => {
+            for error in skipped {
+                eprintln!("Skipped {error}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Stopped on {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_replay(stacks: &mut [Vec<char>], moves: &[Move], crane: &impl Crane, mode: Mode)
+    -> Result<Vec<MoveError>, MoveError>
+{
+    println!("Starting stacks:\n{}", draw(stacks));
+    replay(stacks, moves, crane, mode, |crane_move, stacks| {
+        println!("{crane_move}:\n{}", draw(stacks));
+    })
+}
@@ -0,0 +1,435 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::num::NonZeroUsize;
+use aoc_core::{lines, Line, ParseError, Solution};
+
+pub type ParsedInput = (Vec<Vec<char>>, Vec<Move>);
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (stacks, moves) = parse_input(input)?;
+        Ok(Procedure::new(stacks, moves)?)
+    }
+
+    fn part1(procedure: &Self::Input) -> String {
+        procedure.rearrange(&CrateMover9000)
+    }
+
+    fn part2(procedure: &Self::Input) -> String {
+        procedure.rearrange(&CrateMover9001)
+    }
+}
+
+/// A `move N from A to B` instruction of the rearrangement procedure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    /// Number of the stack to take the crates from, from 1.
+    pub from: usize,
+    /// Number of the stack to put the crates on, from 1.
+    pub to: usize,
+    /// Line of the instruction in the input.
+    pub line: usize,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+/// Reason why a move cannot be done on the current stacks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// There is no stack with the number `stack`.
+    NoSuchStack { line: usize, stack: usize },
+    /// The stack `stack` holds fewer crates than the move takes from it.
+    NotEnoughCrates { line: usize, stack: usize, count: usize, available: usize },
+}
+
+impl MoveError {
+    pub fn line(&self) -> usize {
+        match self {
+            MoveError::NoSuchStack { line, .. } | MoveError::NotEnoughCrates { line, .. } => *line,
+        }
+    }
+
+    fn reason(&self) -> String {
+        match self {
+            MoveError::NoSuchStack { stack, .. } => format!("there is no stack {stack}"),
+            MoveError::NotEnoughCrates { stack, count, available, .. } =>
+                format!("cannot take {count} crates from stack {stack}, which holds {available}"),
+        }
+    }
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line(), self.reason())
+    }
+}
+
+impl Error for MoveError {}
+
+impl From<MoveError> for ParseError {
+    fn from(error: MoveError) -> Self {
+        ParseError::Invalid { line: error.line(), reason: error.reason() }
+    }
+}
+
+impl Move {
+    /// Checks that the move can be done on `stacks`.
+    pub fn check(&self, stacks: &[Vec<char>]) -> Result<(), MoveError> {
+        self.check_heights(&stacks.iter().map(Vec::len).collect::<Vec<_>>())
+    }
+
+    /// Checks that the move can be done on stacks holding `heights` crates.
+    fn check_heights(&self, heights: &[usize]) -> Result<(), MoveError> {
+        let line = self.line;
+        for stack in [self.from, self.to] {
+            if stack == 0 || stack > heights.len() {
+                return Err(MoveError::NoSuchStack { line, stack });
+            }
+        }
+        let available = heights[self.from - 1];
+        if available < self.count {
+            return Err(MoveError::NotEnoughCrates { line, stack: self.from, count: self.count, available });
+        }
+        Ok(())
+    }
+}
+
+/// What to do with a move that cannot be done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Stop the procedure on it.
+    Strict,
+    /// Skip it and go on with the next move.
+    Lenient,
+}
+
+fn fill_stack_by_line(stacks:&mut [Vec<char>], line:&Line) -> Result<(), ParseError> {
+    let mut chars = line.text.chars();
+
+    // Remove first char
+    chars.next();
+
+    // Take the first one
+    let first_stack_char = chars.next().ok_or_else(|| line.missing("crate"))?;
+    // Leave if there is a number
+    if first_stack_char.is_ascii_digit() {return Ok(())}
+    // Add to the stack if it's not a whitespace char
+    if !first_stack_char.is_whitespace() {stacks[0].push(first_stack_char)}
+    
+    // Iterate over the rest
+    let mut next_char;
+    for stack in stacks.iter_mut().skip(1) {
+        next_char = chars.nth(3).ok_or_else(|| line.missing("crate"))?;
+        if !next_char.is_whitespace() {stack.push(next_char)}
+    }
+    Ok(())
+}
+
+/// Reads a `move N from A to B` line.
+fn parse_move(line: &Line) -> Result<Move, ParseError> {
+    let mut words = line.text.split_whitespace();
+    let mut numbers = [0; 3];
+    for (keyword, number) in ["move", "from", "to"].into_iter().zip(numbers.iter_mut()) {
+        let word = line.field(words.next(), keyword)?;
+        if word != keyword {return Err(line.unexpected(word));}
+        *number = line.parse_field(words.next(), "number")?;
+    }
+    if let Some(extra) = words.next() {return Err(line.unexpected(extra));}
+    let [count, from, to] = numbers;
+    Ok(Move { count, from, to, line: line.number })
+}
+
+/// Returns the starting stacks, bottom crate first, and the moves of the
+/// rearrangement procedure. The moves are not checked against the stacks.
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let mut lines = lines(input);
+
+    // Take the first line to initialize the stacks
+    let first_line = lines.next()
+        .ok_or(ParseError::MissingField { line: 1, column: 1, field: "stacks" })?;
+    let n_stacks = (first_line.text.len() + 1) / 4;
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; n_stacks];
+
+    // Fill the stack
+    fill_stack_by_line(&mut stacks, &first_line)?;
+    for line in lines.by_ref() {
+        if !line.text.is_empty() {
+            fill_stack_by_line(&mut stacks, &line)?;
+        }
+        else {break} // Stop when there is a line break
+    }
+
+    // Reverse the stacks because they were filled on reverse order
+    for stack in &mut stacks {
+        stack.reverse();
+    }
+
+    // Read the moves
+    let mut moves = vec![];
+    for line in lines {
+        moves.push(parse_move(&line)?);
+    }
+
+    Ok((stacks, moves))
+}
+
+/// A crane of the supply ship, moving crates between stacks.
+pub trait Crane {
+    /// Most crates the crane can lift at once.
+    fn capacity(&self) -> NonZeroUsize;
+
+    /// Moves the `count` crates on top of the stack `from` onto the stack `to`,
+    /// both indices from 0. The crates lifted together keep their order.
+    fn move_crates(&self, stacks: &mut [Vec<char>], count: usize, from: usize, to: usize) {
+        let mut remaining = count;
+        while remaining > 0 {
+            let lifted = remaining.min(self.capacity().get());
+            let start = stacks[from].len() - lifted;
+            let crates: Vec<char> = stacks[from].drain(start..).collect();
+            stacks[to].extend(crates);
+            remaining -= lifted;
+        }
+    }
+}
+
+/// Moves one crate at a time, reversing the order of the crates it moves.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn capacity(&self) -> NonZeroUsize {
+        NonZeroUsize::MIN
+    }
+}
+
+/// Moves all the crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn capacity(&self) -> NonZeroUsize {
+        NonZeroUsize::MAX
+    }
+}
+
+/// Moves up to `capacity` crates at once.
+pub struct LimitedCrateMover {
+    pub capacity: NonZeroUsize,
+}
+
+impl Crane for LimitedCrateMover {
+    fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+}
+
+/// Draws the stacks like the puzzle does, highest crates first and the
+/// numbers of the stacks below them.
+pub fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row = stacks.iter()
+            .map(|stack| stack.get(level).map_or("   ".to_string(), |item| format!("[{item}]")))
+            .collect::<Vec<_>>();
+        drawing.push_str(row.join(" ").trim_end());
+        drawing.push('\n');
+    }
+    let numbers = (1..=stacks.len()).map(|number| format!(" {number} ")).collect::<Vec<_>>();
+    drawing.push_str(&numbers.join(" "));
+    drawing.push('\n');
+    drawing
+}
+
+/// Letters of the crates on top of each stack. Empty stacks are skipped.
+pub fn top_elements(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+/// Rearranges `stacks` with `crane` following `moves`, calling `on_move` with
+/// each move done and the stacks right after it. A move that cannot be done
+/// stops the procedure in [`Mode::Strict`], and is skipped in [`Mode::Lenient`],
+/// which returns the skipped moves.
+pub fn replay(stacks: &mut [Vec<char>], moves: &[Move], crane: &impl Crane, mode: Mode,
+    mut on_move: impl FnMut(&Move, &[Vec<char>])) -> Result<Vec<MoveError>, MoveError>
+{
+    let mut skipped = vec![];
+    for crane_move in moves {
+        match (crane_move.check(stacks), mode) {
+            (Ok(()), _) => {
+                crane.move_crates(stacks, crane_move.count, crane_move.from - 1, crane_move.to - 1);
+                on_move(crane_move, stacks);
+            }
+            (Err(error), Mode::Strict) => return Err(error),
+            (Err(error), Mode::Lenient) => skipped.push(error),
+        }
+    }
+    Ok(skipped)
+}
+
+/// Rearranges `stacks` with `crane` following `moves`, and returns the crates
+/// on top of each stack, or the first move that cannot be done.
+pub fn rearrange(mut stacks: Vec<Vec<char>>, moves: &[Move], crane: &impl Crane) -> Result<String, MoveError> {
+    replay(&mut stacks, moves, crane, Mode::Strict, |_, _| ())?;
+    Ok(top_elements(&stacks))
+}
+
+/// Checks that every move can be done on the stacks left by the previous ones.
+/// The number of crates on each stack does not depend on the crane, so
+/// neither does the result, and only these numbers are followed.
+pub fn check_moves(stacks: &[Vec<char>], moves: &[Move]) -> Result<(), MoveError> {
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for crane_move in moves {
+        crane_move.check_heights(&heights)?;
+        heights[crane_move.from - 1] -= crane_move.count;
+        heights[crane_move.to - 1] += crane_move.count;
+    }
+    Ok(())
+}
+
+/// A rearrangement procedure whose every move can be done on the stacks left
+/// by the previous ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// The procedure of `moves` on the starting `stacks`, or the first move
+    /// that cannot be done.
+    pub fn new(stacks: Vec<Vec<char>>, moves: Vec<Move>) -> Result<Self, MoveError> {
+        check_moves(&stacks, &moves)?;
+        Ok(Procedure { stacks, moves })
+    }
+
+    /// Starting stacks, bottom crate first.
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Rearranges the stacks with `crane`, and returns the crates on top of
+    /// each stack.
+    pub fn rearrange(&self, crane: &impl Crane) -> String {
+        let mut stacks = self.stacks.clone();
+        for crane_move in &self.moves {
+            crane.move_crates(&mut stacks, crane_move.count, crane_move.from - 1, crane_move.to - 1);
+        }
+        top_elements(&stacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stacks() -> Vec<Vec<char>> {
+        vec![vec!['A', 'B', 'C', 'D', 'E'], vec![]]
+    }
+
+    fn moved(crane: &impl Crane) -> Vec<Vec<char>> {
+        let mut stacks = stacks();
+        crane.move_crates(&mut stacks, 5, 0, 1);
+        stacks
+    }
+
+    #[test]
+    fn cranes_lift_up_to_their_capacity() {
+        assert_eq!(moved(&CrateMover9000), [vec![], vec!['E', 'D', 'C', 'B', 'A']]);
+        assert_eq!(moved(&CrateMover9001), [vec![], vec!['A', 'B', 'C', 'D', 'E']]);
+        let capacity = NonZeroUsize::new(2).unwrap();
+        assert_eq!(moved(&LimitedCrateMover { capacity }), [vec![], vec!['D', 'E', 'B', 'C', 'A']]);
+    }
+
+    // The example of the puzzle, whose lines are padded to the width of the stacks
+    const PROCEDURE: &str = include_str!("../test.txt");
+
+    fn parse_move_line(text: &str) -> Result<Move, ParseError> {
+        parse_move(&Line { number: 7, text })
+    }
+
+    #[test]
+    fn parses_moves() {
+        assert_eq!(parse_move_line("move 12 from 3 to 1").unwrap(), Move { count: 12, from: 3, to: 1, line: 7 });
+        assert_eq!(parse_move_line("move 12 from 3 to 1").unwrap().to_string(), "move 12 from 3 to 1");
+        assert!(matches!(parse_move_line("move 1 form 3 to 1"),
+            Err(ParseError::UnexpectedToken { line: 7, column: 8, token }) if token == "form"));
+        assert!(matches!(parse_move_line("move x from 3 to 1"), Err(ParseError::UnexpectedToken { column: 6, .. })));
+        assert!(matches!(parse_move_line("move 1 from 3"), Err(ParseError::MissingField { field: "to", .. })));
+        assert!(matches!(parse_move_line("move 1 from 3 to 1 now"), Err(ParseError::UnexpectedToken { column: 20, .. })));
+    }
+
+    #[test]
+    fn move_errors() {
+        let stacks = vec![vec!['A'], vec![]];
+        let check = |text| parse_move_line(text).unwrap().check(&stacks);
+        assert_eq!(check("move 1 from 1 to 2"), Ok(()));
+        assert_eq!(check("move 1 from 2 to 1"),
+            Err(MoveError::NotEnoughCrates { line: 7, stack: 2, count: 1, available: 0 }));
+        assert_eq!(check("move 2 from 1 to 2"),
+            Err(MoveError::NotEnoughCrates { line: 7, stack: 1, count: 2, available: 1 }));
+        assert_eq!(check("move 1 from 3 to 2"), Err(MoveError::NoSuchStack { line: 7, stack: 3 }));
+        assert_eq!(check("move 1 from 1 to 0"), Err(MoveError::NoSuchStack { line: 7, stack: 0 }));
+        assert_eq!(check("move 2 from 1 to 2").unwrap_err().to_string(),
+            "line 7: cannot take 2 crates from stack 1, which holds 1");
+    }
+
+    #[test]
+    fn rearranges_a_checked_procedure() {
+        let procedure = Day5::parse(PROCEDURE).unwrap();
+        assert_eq!(Day5::part1(&procedure), "CMZ");
+        assert_eq!(Day5::part2(&procedure), "MCD");
+        let (stacks, moves) = parse_input(PROCEDURE).unwrap();
+        assert_eq!(rearrange(stacks, &moves, &CrateMover9001), Ok("MCD".to_string()));
+    }
+
+    #[test]
+    fn checks_moves_against_the_stacks_left_by_the_previous_ones() {
+        // Stack 2 holds 3 crates, then 2 after the first move
+        let procedure = PROCEDURE.replace("move 2 from 2 to 1", "move 3 from 2 to 1");
+        let error = MoveError::NotEnoughCrates { line: 8, stack: 2, count: 3, available: 2 };
+        assert!(matches!(Day5::parse(&procedure), Err(ParseError::Invalid { line: 8, .. })));
+        let (stacks, moves) = parse_input(&procedure).unwrap();
+        assert_eq!(check_moves(&stacks, &moves), Err(error.clone()));
+        assert_eq!(rearrange(stacks, &moves, &CrateMover9000), Err(error));
+    }
+
+    #[test]
+    fn lenient_replays_skip_the_moves_that_cannot_be_done() {
+        let procedure = PROCEDURE.replace("move 2 from 2 to 1", "move 9 from 2 to 4");
+        let (mut stacks, moves) = parse_input(&procedure).unwrap();
+        let mut done = vec![];
+        let skipped = replay(&mut stacks, &moves, &CrateMover9000, Mode::Lenient, |m, _| done.push(m.line)).unwrap();
+        // Stack 1 is empty by the last move
+        assert_eq!(skipped, [
+            MoveError::NoSuchStack { line: 8, stack: 4 },
+            MoveError::NotEnoughCrates { line: 9, stack: 1, count: 1, available: 0 },
+        ]);
+        assert_eq!(done, [6, 7]);
+        assert_eq!(top_elements(&stacks), "CZ");
+
+        let (mut stacks, moves) = parse_input(&procedure).unwrap();
+        let stopped = replay(&mut stacks, &moves, &CrateMover9000, Mode::Strict, |_, _| ());
+        assert_eq!(stopped, Err(MoveError::NoSuchStack { line: 8, stack: 4 }));
+    }
+
+    #[test]
+    fn moving_no_crate_does_nothing() {
+        let mut stacks = stacks();
+        CrateMover9000.move_crates(&mut stacks, 0, 0, 1);
+        assert_eq!(stacks, self::stacks());
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::ops::Range;
+
+use crate::{for_each_byte, DistinctRun};
+
+/// Window sizes in the table of an analysis, enough for every lowercase letter.
+pub const TABLE_SIZES: Range<usize> = 1..27;
+
+/// The windows of one size in a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Windows {
+    pub size: usize,
+    /// Number of windows of all different bytes, that is of markers.
+    pub distinct: usize,
+    /// Position of the first marker, if there is any.
+    pub first: Option<usize>,
+}
+
+/// What the marker sizes would give on a stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// Number of bytes in the stream.
+    pub len: usize,
+    /// Indices of the longest run of all different bytes, the first one found
+    /// between runs as long. Empty for an empty stream.
+    pub longest: Range<usize>,
+    /// The windows of each size of `TABLE_SIZES`.
+    pub windows: Vec<Windows>,
+}
+
+/// Analyzes the whole of `reader` in a single pass.
+pub fn analyze(reader: impl Read) -> io::Result<Analysis> {
+    let mut windows: Vec<Windows> = TABLE_SIZES
+        .map(|size| Windows { size, distinct: 0, first: None })
+        .collect();
+    let mut run = DistinctRun::default();
+    let mut longest = 0..0;
+    for_each_byte(reader, |byte| {
+        let length = run.push(byte);
+        if length > longest.len() {
+            longest = run.read() - length..run.read();
+        }
+        for window in windows.iter_mut().take_while(|window| window.size <= length) {
+            window.distinct += 1;
+            window.first.get_or_insert(run.read());
+        }
+    })?;
+
+    Ok(Analysis { len: run.read(), longest, windows })
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} bytes, longest run of different bytes: {} at {}..{}",
+            self.len, self.longest.len(), self.longest.start, self.longest.end)?;
+        write!(f, "size  first  distinct windows")?;
+        for window in &self.windows {
+            let first = window.first.map_or("-".to_string(), |first| first.to_string());
+            // A stream has a window of each size up to its length
+            let total = (self.len + 1).saturating_sub(window.size);
+            write!(f, "\n{:>4}  {first:>5}  {}/{total}", window.size, window.distinct)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_run_and_windows() {
+        let analysis = analyze(&b"abcabdefa"[..]).unwrap();
+        assert_eq!(analysis.len, 9);
+        // "cabdef", found before "bdefa"
+        assert_eq!(analysis.longest, 2..8);
+        assert_eq!(analysis.windows.len(), TABLE_SIZES.len());
+        assert_eq!(analysis.windows[0], Windows { size: 1, distinct: 9, first: Some(1) });
+        assert_eq!(analysis.windows[2], Windows { size: 3, distinct: 7, first: Some(3) });
+        assert_eq!(analysis.windows[5], Windows { size: 6, distinct: 1, first: Some(8) });
+        assert_eq!(analysis.windows[6], Windows { size: 7, distinct: 0, first: None });
+    }
+
+    #[test]
+    fn empty_stream() {
+        let analysis = analyze(&b""[..]).unwrap();
+        assert_eq!((analysis.len, analysis.longest), (0, 0..0));
+        assert!(analysis.windows.iter().all(|windows| windows.distinct == 0 && windows.first.is_none()));
+    }
+
+    #[test]
+    fn table() {
+        let table = analyze(&b"aab"[..]).unwrap().to_string();
+        let mut lines = table.lines();
+        assert_eq!(lines.next(), Some("3 bytes, longest run of different bytes: 2 at 1..3"));
+        assert_eq!(lines.next(), Some("size  first  distinct windows"));
+        assert_eq!(lines.next(), Some("   1      1  3/3"));
+        assert_eq!(lines.next(), Some("   2      3  1/2"));
+        assert_eq!(lines.next(), Some("   3      -  0/1"));
+        assert_eq!(lines.next(), Some("   4      -  0/0"));
+        assert_eq!(lines.count(), 22);
+    }
+}
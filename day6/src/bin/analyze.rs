@@ -0,0 +1,37 @@
+use std::fs::File;
+use std::io;
+use std::process::ExitCode;
+use aoc_core::DEFAULT_INPUT;
+use clap::Parser;
+use day6::analyze;
+
+/// Prints, for every marker size from 1 to 26, the position of the first marker
+/// and how many windows of that size are markers, along with the longest run of
+/// different bytes. Every byte of the capture counts, line breaks included.
+#[derive(Parser)]
+struct Args {
+    /// Signal capture, or `-` to read it from stdin
+    #[arg(default_value = DEFAULT_INPUT)]
+    capture: String,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let analysis = if args.capture == "-" {
+        analyze(io::stdin().lock())
+    } else {
+        File::open(&args.capture).and_then(analyze)
+    };
+
+    match analysis {
+        Ok(analysis) => {
+            println!("{analysis}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Cannot read {}: {error}", args.capture);
+            ExitCode::FAILURE
+        }
+    }
+}
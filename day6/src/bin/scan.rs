@@ -0,0 +1,58 @@
+use std::fs::File;
+use std::io;
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+use aoc_core::DEFAULT_INPUT;
+use clap::Parser;
+use day6::{scan_markers, Marker};
+
+/// Scans a signal capture for markers of several sizes at once, without
+/// loading it in memory. Every byte of the capture counts, line breaks
+/// included.
+#[derive(Parser)]
+struct Args {
+    /// Signal capture, or `-` to read it from stdin
+    #[arg(default_value = DEFAULT_INPUT)]
+    capture: String,
+
+    /// Numbers of different bytes of the markers
+    #[arg(default_values = ["4", "14"])]
+    sizes: Vec<NonZeroUsize>,
+}
+
+/// The markers of one size found so far.
+struct Found {
+    size: NonZeroUsize,
+    count: usize,
+    first: Option<usize>,
+}
+
+fn main() -> ExitCode {
+    let mut args = Args::parse();
+    args.sizes.sort_unstable();
+    args.sizes.dedup();
+
+    let mut found: Vec<Found> = args.sizes.iter().map(|&size| Found { size, count: 0, first: None }).collect();
+    let on_marker = |marker: Marker| {
+        let found = found.iter_mut().find(|found| found.size == marker.size).unwrap();
+        found.count += 1;
+        found.first.get_or_insert(marker.position);
+    };
+    let scanned = if args.capture == "-" {
+        scan_markers(io::stdin().lock(), &args.sizes, on_marker)
+    } else {
+        File::open(&args.capture).and_then(|file| scan_markers(file, &args.sizes, on_marker))
+    };
+
+    if let Err(error) = scanned {
+        eprintln!("Cannot read {}: {error}", args.capture);
+        return ExitCode::FAILURE;
+    }
+    for found in found {
+        match found.first {
+            Some(first) => println!("{:>2}: {} markers, the first in position {first}", found.size, found.count),
+            None => println!("{:>2}: no marker", found.size),
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,199 @@
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::num::NonZeroUsize;
+use aoc_core::{lines, ParseError, Solution};
+
+mod analysis;
+
+pub use analysis::{analyze, Analysis, Windows, TABLE_SIZES};
+
+const PACKET_MARKER: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+const MESSAGE_MARKER: NonZeroUsize = NonZeroUsize::new(14).unwrap();
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<u8>;
+    type Part1 = FirstMarker;
+    type Part2 = FirstMarker;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(datastream: &Self::Input) -> FirstMarker {
+        FirstMarker(first_marker(datastream, PACKET_MARKER))
+    }
+
+    fn part2(datastream: &Self::Input) -> FirstMarker {
+        FirstMarker(first_marker(datastream, MESSAGE_MARKER))
+    }
+}
+
+/// Answer of a part: the position of the first marker, or `None` when the
+/// datastream has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstMarker(pub Option<usize>);
+
+impl Display for FirstMarker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(position) => write!(f, "{position}"),
+            None => write!(f, "no marker"),
+        }
+    }
+}
+
+/// Returns the datastream, which is the only line of the input. Use
+/// [`scan_markers`] for streams spanning several lines.
+pub fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    let mut lines = lines(input).filter(|line| !line.text.is_empty());
+    let datastream = lines.next().map_or(vec![], |line| line.text.as_bytes().to_vec());
+    if let Some(line) = lines.next() {
+        return Err(ParseError::Invalid { line: line.number, reason: "expected a single datastream".to_string() });
+    }
+    Ok(datastream)
+}
+
+/// Follows a stream of bytes, tracking the longest run of all different bytes
+/// ending on the last byte read.
+#[derive(Debug, Clone)]
+pub struct DistinctRun {
+    /// Index of the last occurrence of each byte.
+    last_seen: [Option<usize>; 256],
+    /// Index of the first byte of the run.
+    start: usize,
+    /// Number of bytes read so far.
+    read: usize,
+}
+
+impl Default for DistinctRun {
+    fn default() -> Self {
+        DistinctRun { last_seen: [None; 256], start: 0, read: 0 }
+    }
+}
+
+impl DistinctRun {
+    /// Reads `byte` and returns the length of the run of all different bytes
+    /// ending on it.
+    pub fn push(&mut self, byte: u8) -> usize {
+        if let Some(last_idx) = self.last_seen[byte as usize] {
+            self.start = self.start.max(last_idx + 1);
+        }
+        self.last_seen[byte as usize] = Some(self.read);
+        self.read += 1;
+        self.read - self.start
+    }
+
+    /// Number of bytes read so far, which is the position of a marker ending
+    /// on the last byte.
+    pub fn read(&self) -> usize {
+        self.read
+    }
+}
+
+/// A marker found in a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    /// Number of different bytes of the marker.
+    pub size: NonZeroUsize,
+    /// Number of bytes read when the marker is complete.
+    pub position: usize,
+}
+
+/// Returns the number of bytes read until the last `size` ones are all
+/// different, or `None` if that never happens.
+pub fn first_marker(datastream: &[u8], size: NonZeroUsize) -> Option<usize> {
+    let mut run = DistinctRun::default();
+    datastream.iter().position(|&byte| run.push(byte) >= size.get()).map(|idx| idx + 1)
+}
+
+/// Finds every marker of each of `sizes` in a single pass over `reader`,
+/// calling `on_marker` with each of them as soon as it is complete, in the
+/// order of `sizes` for the markers ending on the same byte. The stream is
+/// read in chunks and the markers are not kept, so the stream can be of any
+/// length. Every byte counts, line breaks included.
+pub fn scan_markers(reader: impl Read, sizes: &[NonZeroUsize], mut on_marker: impl FnMut(Marker)) -> io::Result<()> {
+    let mut run = DistinctRun::default();
+    for_each_byte(reader, |byte| {
+        let length = run.push(byte);
+        for &size in sizes.iter().filter(|size| size.get() <= length) {
+            on_marker(Marker { size, position: run.read() });
+        }
+    })
+}
+
+/// Calls `f` on every byte of `reader`, reading it in chunks.
+pub(crate) fn for_each_byte(mut reader: impl Read, mut f: impl FnMut(u8)) -> io::Result<()> {
+    let mut buffer = [0; 64 * 1024];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => buffer[..read].iter().for_each(|&byte| f(byte)),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size(size: usize) -> NonZeroUsize {
+        NonZeroUsize::new(size).unwrap()
+    }
+
+    #[test]
+    fn first_markers_of_the_examples() {
+        for (datastream, packet, message) in [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ] {
+            let datastream = Day6::parse(datastream).unwrap();
+            let expected = (FirstMarker(Some(packet)), FirstMarker(Some(message)));
+            assert_eq!((Day6::part1(&datastream), Day6::part2(&datastream)), expected);
+        }
+    }
+
+    #[test]
+    fn no_marker() {
+        assert_eq!(first_marker(b"abab", size(3)), None);
+        assert_eq!(first_marker(b"", size(1)), None);
+        assert_eq!(Day6::part1(&b"aaaaaa".to_vec()), FirstMarker(None));
+        assert_eq!(FirstMarker(None).to_string(), "no marker");
+        assert_eq!(FirstMarker(Some(7)).to_string(), "7");
+    }
+
+    #[test]
+    fn markers_over_any_byte() {
+        assert_eq!(first_marker(&[0, 255, 0, 200, 255], size(3)), Some(4));
+        assert_eq!(first_marker("éé".as_bytes(), size(2)), Some(2));
+    }
+
+    #[test]
+    fn a_single_datastream_per_input() {
+        assert_eq!(parse_input("abc\n\n").unwrap(), b"abc");
+        assert_eq!(parse_input("").unwrap(), b"");
+        assert!(matches!(parse_input("abc\n\ndef"), Err(ParseError::Invalid { line: 3, .. })));
+    }
+
+    #[test]
+    fn scans_every_marker_of_several_sizes() {
+        let mut markers = vec![];
+        // The line break counts as any other byte
+        scan_markers(&b"aab\ncab"[..], &[size(3), size(1), size(4)], |marker| {
+            markers.push((marker.size.get(), marker.position));
+        }).unwrap();
+        assert_eq!(markers, [
+            (1, 1), (1, 2), (1, 3),
+            (3, 4), (1, 4),
+            (3, 5), (1, 5), (4, 5),
+            (3, 6), (1, 6), (4, 6),
+            (3, 7), (1, 7), (4, 7),
+        ]);
+    }
+}
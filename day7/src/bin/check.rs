@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, DEFAULT_INPUT};
+use clap::Parser;
+use day7::{read_transcript, Listings, ROOT};
+
+/// Lists the issues of a terminal output with their line numbers, then the size
+/// of the root folder. Exits with a failure when there is any issue.
+#[derive(Parser)]
+struct Args {
+    /// Terminal output
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+
+    /// Does not count twice the files of a folder listed again
+    #[arg(long)]
+    reconcile: bool,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+    let listings = if args.reconcile {Listings::Reconcile} else {Listings::Sum};
+
+    let transcript = match read_transcript(&input, listings) {
+        Ok(transcript) => transcript,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for issue in &transcript.issues {
+        println!("{issue}");
+    }
+    println!("{} issues, the root folder holds {}", transcript.issues.len(), transcript.filesystem.sizes()[ROOT]);
+
+    if transcript.issues.is_empty() {ExitCode::SUCCESS} else {ExitCode::FAILURE}
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, DEFAULT_INPUT};
+use clap::Parser;
+use day7::{parse_input, plan_cleanup};
+
+/// Prints the sets of folders to delete to free enough space, from the one
+/// wasting the least.
+#[derive(Parser)]
+struct Args {
+    /// Terminal output
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+
+    /// Size of the disk
+    #[arg(default_value_t = 70000000)]
+    disk_space: u32,
+
+    /// Free space needed for the update
+    #[arg(default_value_t = 30000000)]
+    space_needed: u32,
+
+    /// Number of plans to print
+    #[arg(default_value_t = 5)]
+    alternatives: usize,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+
+    let filesystem = match parse_input(&input) {
+        Ok(filesystem) => filesystem,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let plans = plan_cleanup(&filesystem, args.disk_space, args.space_needed, args.alternatives);
+    if plans.is_empty() {
+        println!("Deleting everything would not free enough space");
+    }
+    for plan in plans {
+        if plan.folders.is_empty() {
+            println!("There is already enough free space");
+            continue;
+        }
+        let paths: Vec<String> = plan.folders.iter().map(|&folder| filesystem.path(folder)).collect();
+        println!("{} wasted, {} freed: {}", plan.waste, plan.size, paths.join(" "));
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, DEFAULT_INPUT};
+use clap::{Parser, Subcommand};
+use day7::{parse_input, Entry};
+
+/// Explores the filesystem of a terminal output.
+#[derive(Parser)]
+struct Args {
+    /// Terminal output
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+
+    #[command(subcommand)]
+    query: Option<Query>,
+}
+
+#[derive(Subcommand)]
+enum Query {
+    /// Draws the whole tree, the default
+    Tree,
+    /// Lists the folders from the biggest to the smallest
+    Du,
+    /// Lists the folders and files named NAME
+    Find { name: String },
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+
+    let filesystem = match parse_input(&input) {
+        Ok(filesystem) => filesystem,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.query.unwrap_or(Query::Tree) {
+        Query::Tree => println!("{}", filesystem.tree()),
+        Query::Du => {
+            let mut folders = filesystem.du();
+            folders.sort_by_key(|folder| std::cmp::Reverse(folder.size));
+            print_entries(&folders);
+        }
+        Query::Find { name } => print_entries(&filesystem.find(&name)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_entries(entries: &[Entry]) {
+    let width = entries.iter().map(|entry| entry.size.to_string().len()).max().unwrap_or(0);
+    for entry in entries {
+        let slash = if entry.is_folder() && entry.path != "/" {"/"} else {""};
+        println!("{:>width$}  {}{slash}", entry.size, entry.path);
+    }
+}
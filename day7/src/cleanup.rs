@@ -0,0 +1,252 @@
+use std::collections::BinaryHeap;
+
+use crate::{FileSystem, ROOT};
+
+/// Folders to delete together, none of them inside another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// Indices of the folders, in increasing order.
+    pub folders: Vec<usize>,
+    /// Total size of the folders.
+    pub size: u32,
+    /// Space freed beyond what was needed.
+    pub waste: u32,
+}
+
+/// Finds the sets of folders to delete so that `disk_space` has `space_needed`
+/// free, none of them inside another and each of them needed, that is freeing
+/// too little without any one of its folders. Returns up to `alternatives` of
+/// the sets wasting the least space, from the one wasting the least to the one
+/// wasting the most. When more sets waste as little, any of them may be
+/// returned. Returns a single empty plan when there is already enough space.
+pub fn plan_cleanup(filesystem: &FileSystem, disk_space: u32, space_needed: u32, alternatives: usize) -> Vec<Plan> {
+    let sizes = filesystem.sizes();
+    // In u64, as the used and the needed space may add up beyond a u32
+    let to_free = (u64::from(sizes[ROOT]) + u64::from(space_needed)).saturating_sub(u64::from(disk_space));
+    if to_free == 0 {
+        return vec![Plan { folders: vec![], size: 0, waste: 0 }];
+    }
+    // More than the root holds cannot be freed
+    let Ok(to_free) = u32::try_from(to_free) else {return vec![]};
+
+    let mut search = Search::new(filesystem, sizes, alternatives);
+    search.extend(0, to_free);
+
+    let mut plans: Vec<Plan> = search.best.into_iter()
+        .map(|(waste, mut folders)| {
+            folders.sort_unstable();
+            Plan { size: to_free + waste, folders, waste }
+        })
+        .collect();
+    plans.sort_by(|a, b| (a.waste, a.folders.len(), &a.folders).cmp(&(b.waste, b.folders.len(), &b.folders)));
+    plans
+}
+
+/// A branch and bound over the sets of folders, taken from the biggest to the
+/// smallest. A set stops growing as soon as it frees enough space, so it is
+/// needed: without its smallest folder, the last one taken, it would not have.
+struct Search {
+    sizes: Vec<u32>,
+    /// Every folder, from the biggest to the smallest.
+    order: Vec<usize>,
+    /// Total size of the folders of `order` from each index on.
+    remaining: Vec<u64>,
+    /// Index of each folder in a walk of the tree, and index right after the
+    /// folders inside it.
+    spans: Vec<(usize, usize)>,
+    /// The folders taken so far.
+    taken: Vec<usize>,
+    /// The plans found wasting the least, by waste, the worst on top.
+    best: BinaryHeap<(u32, Vec<usize>)>,
+    alternatives: usize,
+}
+
+impl Search {
+    fn new(filesystem: &FileSystem, sizes: Vec<u32>, alternatives: usize) -> Self {
+        let mut order: Vec<usize> = (0..sizes.len()).collect();
+        order.sort_by_key(|&folder| std::cmp::Reverse(sizes[folder]));
+        let mut remaining = vec![0; order.len() + 1];
+        for idx in (0..order.len()).rev() {
+            remaining[idx] = remaining[idx + 1] + u64::from(sizes[order[idx]]);
+        }
+        let spans = spans(filesystem);
+        Search { sizes, order, remaining, spans, taken: vec![], best: BinaryHeap::new(), alternatives }
+    }
+
+    /// Waste a new plan must beat, or `None` while there are not enough plans.
+    fn worst(&self) -> Option<u32> {
+        (self.best.len() == self.alternatives).then(|| self.best.peek().map_or(0, |&(waste, _)| waste))
+    }
+
+    /// Whether `folder` is neither inside nor around a folder already taken.
+    fn fits(&self, folder: usize) -> bool {
+        let (start, end) = self.spans[folder];
+        self.taken.iter().all(|&other| {
+            let (other_start, other_end) = self.spans[other];
+            !(other_start..other_end).contains(&start) && !(start..end).contains(&other_start)
+        })
+    }
+
+    /// Adds to the folders taken those of `order` from `from` on, until
+    /// freeing at least `to_free` more.
+    fn extend(&mut self, from: usize, to_free: u32) {
+        for idx in from..self.order.len() {
+            let worst = self.worst();
+            // Nothing wastes less than nothing
+            if worst == Some(0) || self.remaining[idx] < u64::from(to_free) {
+                return;
+            }
+            let (folder, size) = (self.order[idx], self.sizes[self.order[idx]]);
+            if worst.is_some_and(|worst| u64::from(size) >= u64::from(to_free) + u64::from(worst)) || !self.fits(folder) {
+                continue;
+            }
+
+            self.taken.push(folder);
+            if size >= to_free {
+                self.best.push((size - to_free, self.taken.clone()));
+                if self.best.len() > self.alternatives {
+                    self.best.pop();
+                }
+            } else {
+                self.extend(idx + 1, to_free - size);
+            }
+            self.taken.pop();
+        }
+    }
+}
+
+/// Index of each folder in a walk of the tree, each folder followed by the
+/// ones inside it, with the index right after them.
+fn spans(filesystem: &FileSystem) -> Vec<(usize, usize)> {
+    let mut spans = vec![(0, 0); filesystem.folders().len()];
+    let mut walk = vec![];
+    let mut stack = vec![ROOT];
+    while let Some(folder) = stack.pop() {
+        spans[folder].0 = walk.len();
+        walk.push(folder);
+        stack.extend(filesystem.folder(folder).folders.iter().rev());
+    }
+    // Going backwards, each folder ends after the end of its last subfolder
+    for &folder in walk.iter().rev() {
+        let start = spans[folder].0;
+        spans[folder].1 = filesystem.folder(folder).folders.iter().map(|&sub| spans[sub].1).max().unwrap_or(start + 1);
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `/a` of 5, `/b` of 4, `/c` of 3 and `/d` of 1.
+    fn flat() -> FileSystem {
+        let mut filesystem = FileSystem::default();
+        for (name, size) in [("a", 5), ("b", 4), ("c", 3), ("d", 1)] {
+            let folder = filesystem.get_or_create_folder(ROOT, name);
+            filesystem.add_file(folder, "f", size);
+        }
+        filesystem
+    }
+
+    fn paths(filesystem: &FileSystem, plans: &[Plan]) -> Vec<(u32, Vec<String>)> {
+        plans.iter()
+            .map(|plan| (plan.waste, plan.folders.iter().map(|&folder| filesystem.path(folder)).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn every_set_reaching_a_size_is_considered() {
+        let filesystem = flat();
+        let plans = plan_cleanup(&filesystem, 100, 95, 5);
+        assert_eq!(paths(&filesystem, &plans), [
+            (0, vec!["/a".to_string(), "/c".to_string()]),
+            (0, vec!["/b".to_string(), "/c".to_string(), "/d".to_string()]),
+            (1, vec!["/a".to_string(), "/b".to_string()]),
+            (5, vec!["/".to_string()]),
+        ]);
+        assert_eq!(plans[2].size, 9);
+        assert_eq!(plan_cleanup(&filesystem, 100, 95, 1).len(), 1);
+        assert_eq!(plan_cleanup(&filesystem, 100, 95, 0), []);
+    }
+
+    #[test]
+    fn enough_space_or_too_little() {
+        let filesystem = flat();
+        assert_eq!(plan_cleanup(&filesystem, 100, 87, 5), [Plan { folders: vec![], size: 0, waste: 0 }]);
+        assert_eq!(plan_cleanup(&filesystem, 100, 101, 5), []);
+        assert_eq!(plan_cleanup(&filesystem, 0, u32::MAX, 5), []);
+        // Everything, as the root or as all of its folders
+        let plans = plan_cleanup(&filesystem, u32::MAX, u32::MAX, 5);
+        assert_eq!(plans.iter().map(|plan| (plan.size, plan.waste)).collect::<Vec<_>>(), [(13, 0), (13, 0)]);
+    }
+
+    #[test]
+    fn folders_inside_each_other_are_not_deleted_together() {
+        let filesystem = crate::parse_input(include_str!("../test.txt")).unwrap();
+        let plans = plan_cleanup(&filesystem, 70000000, 30000000, 3);
+        assert_eq!(paths(&filesystem, &plans), [
+            (24933642 - 8381165, vec!["/d".to_string()]),
+            (48381165 - 8381165, vec!["/".to_string()]),
+        ]);
+    }
+
+    /// The waste of every needed set, from the least.
+    fn brute_force(sizes: &[u32], parents: &[Option<usize>], to_free: u32) -> Vec<u32> {
+        let inside = |mut folder: usize, other: usize| loop {
+            match parents[folder] {
+                Some(parent) if parent == other => return true,
+                Some(parent) => folder = parent,
+                None => return false,
+            }
+        };
+        let mut wastes = vec![];
+        for set in 1..1u32 << sizes.len() {
+            let folders: Vec<usize> = (0..sizes.len()).filter(|&folder| set & 1 << folder != 0).collect();
+            let total: u32 = folders.iter().map(|&folder| sizes[folder]).sum();
+            let apart = folders.iter().all(|&a| folders.iter().all(|&b| !inside(a, b)));
+            let needed = folders.iter().all(|&folder| total - sizes[folder] < to_free);
+            if apart && total >= to_free && needed {
+                wastes.push(total - to_free);
+            }
+        }
+        wastes.sort_unstable();
+        wastes
+    }
+
+    #[test]
+    fn matches_a_brute_force() {
+        let mut state = 0x2545f491u32;
+        let mut random = |bound: u32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % bound
+        };
+        for _ in 0..200 {
+            let mut filesystem = FileSystem::default();
+            let count = 1 + random(11) as usize;
+            for folder in 0..count {
+                let sub = match folder {
+                    0 => ROOT,
+                    _ => filesystem.get_or_create_folder(random(folder as u32) as usize, &folder.to_string()),
+                };
+                filesystem.add_file(sub, "f", random(20));
+            }
+            let sizes = filesystem.sizes();
+            let parents: Vec<Option<usize>> = filesystem.folders().iter().map(|folder| folder.parent).collect();
+            let to_free = 1 + random(sizes[ROOT] + 1);
+            let alternatives = 1 + random(6) as usize;
+            let plans = plan_cleanup(&filesystem, sizes[ROOT] + 10, to_free + 10, alternatives);
+
+            let wastes = brute_force(&sizes, &parents, to_free);
+            let expected = &wastes[..wastes.len().min(alternatives)];
+            assert_eq!(plans.iter().map(|plan| plan.waste).collect::<Vec<_>>(), expected);
+            for plan in &plans {
+                let total: u32 = plan.folders.iter().map(|&folder| sizes[folder]).sum();
+                assert_eq!((plan.size, plan.waste), (total, total - to_free));
+                assert!(plan.folders.iter().all(|&folder| sizes[folder] > plan.waste));
+            }
+            assert!(plans.windows(2).all(|pair| pair[0].folders != pair[1].folders));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt::{self, Display};
+
+/// Index of the root folder, `/`.
+pub const ROOT: usize = 0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub name: String,
+    pub size: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Folder {
+    pub name: String,
+    /// Index of the folder containing this one, `None` for the root.
+    pub parent: Option<usize>,
+    pub files: Vec<File>,
+    /// Indices of the folders in this one.
+    pub folders: Vec<usize>,
+}
+
+impl Folder {
+    fn new(name: &str, parent: Option<usize>) -> Self {
+        Folder { name: name.to_string(), parent, files: vec![], folders: vec![] }
+    }
+}
+
+/// A file or a folder found walking the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry<'a> {
+    /// Full path, such as `/a/e/i`.
+    pub path: String,
+    pub name: &'a str,
+    /// Size of the file, or total size of the folder.
+    pub size: u32,
+    /// Index of the folder, `None` for a file.
+    pub folder: Option<usize>,
+    /// Number of folders above the entry, 0 for the root.
+    pub depth: usize,
+}
+
+impl Entry<'_> {
+    pub fn is_folder(&self) -> bool {
+        self.folder.is_some()
+    }
+}
+
+/// The folders and files of the device, as explored in the terminal. A folder
+/// always comes after the one containing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    folders: Vec<Folder>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem { folders: vec![Folder::new("/", None)] }
+    }
+}
+
+impl FileSystem {
+    pub fn folders(&self) -> &[Folder] {
+        &self.folders
+    }
+
+    pub fn folder(&self, folder: usize) -> &Folder {
+        &self.folders[folder]
+    }
+
+    /// Folder `name` in `folder`, if it is known.
+    pub fn subfolder(&self, folder: usize, name: &str) -> Option<usize> {
+        self.folders[folder].folders.iter().copied().find(|&sub| self.folders[sub].name == name)
+    }
+
+    /// Folder `name` in `folder`, created if it is not known yet, so that
+    /// coming back to a folder finds what was already seen in it.
+    pub fn get_or_create_folder(&mut self, folder: usize, name: &str) -> usize {
+        if let Some(sub) = self.subfolder(folder, name) {
+            return sub;
+        }
+        self.folders.push(Folder::new(name, Some(folder)));
+        let sub = self.folders.len() - 1;
+        self.folders[folder].folders.push(sub);
+        sub
+    }
+
+    pub fn add_file(&mut self, folder: usize, name: &str, size: u32) {
+        self.folders[folder].files.push(File { name: name.to_string(), size });
+    }
+
+    /// Adds a file, or sets the size of the file `name` already in `folder`.
+    pub fn set_file(&mut self, folder: usize, name: &str, size: u32) {
+        match self.folders[folder].files.iter_mut().find(|file| file.name == name) {
+            Some(file) => file.size = size,
+            None => self.add_file(folder, name, size),
+        }
+    }
+
+    /// Full path of `folder`, such as `/a/e`.
+    pub fn path(&self, folder: usize) -> String {
+        let mut names = vec![];
+        let mut current = folder;
+        while let Some(parent) = self.folders[current].parent {
+            names.push(self.folders[current].name.as_str());
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Folder at the full path `path`, if it is known.
+    pub fn lookup(&self, path: &str) -> Option<usize> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(ROOT, |folder, name| self.subfolder(folder, name))
+    }
+
+    /// Total size of every folder, by index.
+    pub fn sizes(&self) -> Vec<u32> {
+        let mut sizes: Vec<u32> = self.folders.iter()
+            .map(|folder| folder.files.iter().map(|file| file.size).sum())
+            .collect();
+        // Folders come after their parent, so going backwards adds each folder
+        // to its parent once it is complete
+        for folder in (0..self.folders.len()).rev() {
+            if let Some(parent) = self.folders[folder].parent {
+                sizes[parent] += sizes[folder];
+            }
+        }
+        sizes
+    }
+
+    /// Every folder and file, each folder followed by its content sorted by
+    /// name, as `tree` lists them.
+    pub fn entries(&self) -> Vec<Entry<'_>> {
+        let sizes = self.sizes();
+        let mut entries = vec![];
+        self.walk(ROOT, "/".to_string(), 0, &sizes, &mut entries);
+        entries
+    }
+
+    fn walk<'a>(&'a self, folder: usize, path: String, depth: usize, sizes: &[u32], entries: &mut Vec<Entry<'a>>) {
+        let current = &self.folders[folder];
+        let prefix = if folder == ROOT {String::new()} else {path.clone()};
+        entries.push(Entry { path, name: &current.name, size: sizes[folder], folder: Some(folder), depth });
+
+        let mut content: Vec<(&str, Option<usize>, u32)> = current.folders.iter()
+            .map(|&sub| (self.folders[sub].name.as_str(), Some(sub), sizes[sub]))
+            .chain(current.files.iter().map(|file| (file.name.as_str(), None, file.size)))
+            .collect();
+        content.sort_by_key(|&(name, _, _)| name);
+
+        for (name, sub, size) in content {
+            let path = format!("{prefix}/{name}");
+            match sub {
+                Some(sub) => self.walk(sub, path, depth + 1, sizes, entries),
+                None => entries.push(Entry { path, name, size, folder: None, depth: depth + 1 }),
+            }
+        }
+    }
+
+    /// Every folder and file named `name`.
+    pub fn find(&self, name: &str) -> Vec<Entry<'_>> {
+        self.entries().into_iter().filter(|entry| entry.name == name).collect()
+    }
+
+    /// Every folder with its total size, as `du` lists them.
+    pub fn du(&self) -> Vec<Entry<'_>> {
+        self.entries().into_iter().filter(Entry::is_folder).collect()
+    }
+
+    /// Draws the tree as in the puzzle, one entry per line.
+    pub fn tree(&self) -> impl Display + '_ {
+        Tree(self)
+    }
+}
+
+struct Tree<'a>(&'a FileSystem);
+
+impl Display for Tree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, entry) in self.0.entries().iter().enumerate() {
+            if idx > 0 {writeln!(f)?;}
+            let indent = "  ".repeat(entry.depth);
+            match entry.folder {
+                Some(_) => write!(f, "{indent}- {} (dir, size={})", entry.name, entry.size)?,
+                None => write!(f, "{indent}- {} (file, size={})", entry.name, entry.size)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `/` holding `b.txt` and `a/`, which holds `e/` and `f`, and `e/` holding `i`.
+    fn example() -> FileSystem {
+        let mut filesystem = FileSystem::default();
+        filesystem.add_file(ROOT, "b.txt", 100);
+        let a = filesystem.get_or_create_folder(ROOT, "a");
+        let e = filesystem.get_or_create_folder(a, "e");
+        filesystem.add_file(a, "f", 20);
+        filesystem.add_file(e, "i", 3);
+        filesystem
+    }
+
+    #[test]
+    fn revisited_folders_are_not_created_again() {
+        let mut filesystem = example();
+        let a = filesystem.lookup("/a").unwrap();
+        assert_eq!(filesystem.get_or_create_folder(ROOT, "a"), a);
+        assert_eq!(filesystem.folders().len(), 3);
+        assert_eq!(filesystem.folder(ROOT).folders, [a]);
+    }
+
+    #[test]
+    fn paths_and_lookups() {
+        let filesystem = example();
+        let e = filesystem.lookup("/a/e").unwrap();
+        assert_eq!(filesystem.path(e), "/a/e");
+        assert_eq!(filesystem.path(ROOT), "/");
+        assert_eq!(filesystem.lookup("/"), Some(ROOT));
+        assert_eq!(filesystem.lookup("a/e/"), Some(e));
+        assert_eq!(filesystem.lookup("/a/f"), None);
+        assert_eq!(filesystem.lookup("/e"), None);
+    }
+
+    #[test]
+    fn sizes_include_subfolders() {
+        let mut filesystem = example();
+        assert_eq!(filesystem.sizes(), [123, 23, 3]);
+        filesystem.set_file(ROOT, "b.txt", 10);
+        filesystem.set_file(ROOT, "c", 1);
+        assert_eq!(filesystem.sizes(), [34, 23, 3]);
+    }
+
+    #[test]
+    fn du_lists_folders_depth_first() {
+        let filesystem = example();
+        let folders: Vec<(String, u32)> = filesystem.du().into_iter().map(|entry| (entry.path, entry.size)).collect();
+        assert_eq!(folders, [("/".to_string(), 123), ("/a".to_string(), 23), ("/a/e".to_string(), 3)]);
+    }
+
+    #[test]
+    fn find_by_name() {
+        let mut filesystem = example();
+        let e = filesystem.lookup("/a/e").unwrap();
+        filesystem.get_or_create_folder(e, "f");
+        let found: Vec<(String, bool)> = filesystem.find("f").into_iter().map(|entry| (entry.path.clone(), entry.is_folder())).collect();
+        assert_eq!(found, [("/a/e/f".to_string(), true), ("/a/f".to_string(), false)]);
+        assert!(filesystem.find("z").is_empty());
+    }
+
+    #[test]
+    fn tree_sorts_the_content_by_name() {
+        assert_eq!(example().tree().to_string(), "\
+- / (dir, size=123)
+  - a (dir, size=23)
+    - e (dir, size=3)
+      - i (file, size=3)
+    - f (file, size=20)
+  - b.txt (file, size=100)");
+    }
+}
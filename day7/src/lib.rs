@@ -0,0 +1,65 @@
+use aoc_core::{ParseError, Solution};
+
+mod cleanup;
+mod filesystem;
+mod transcript;
+
+pub use cleanup::{plan_cleanup, Plan};
+pub use filesystem::{Entry, File, FileSystem, Folder, ROOT};
+pub use transcript::{read_transcript, Issue, Listings, Transcript};
+
+const SMALL_FOLDER_SIZE: u32 = 100000;
+const DISK_SPACE: u32 = 70000000;
+const SPACE_NEEDED: u32 = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(filesystem: &Self::Input) -> u32 {
+        compute_sum_smaller_than(filesystem, SMALL_FOLDER_SIZE)
+    }
+
+    fn part2(filesystem: &Self::Input) -> u32 {
+        smallest_folder_to_delete(filesystem, DISK_SPACE, SPACE_NEEDED)
+    }
+}
+
+/// Reads the terminal output `input`, going on after its issues as the device
+/// would: every listing adds its files. `read_transcript` reports the issues.
+pub fn parse_input(input: &str) -> Result<FileSystem, ParseError> {
+    Ok(read_transcript(input, Listings::Sum)?.filesystem)
+}
+
+pub fn compute_sum_smaller_than(filesystem: &FileSystem, smaller_than: u32) -> u32 {
+    filesystem.sizes().into_iter().filter(|&size| size <= smaller_than).sum()
+}
+
+pub fn smallest_folder_to_delete(filesystem: &FileSystem, disk_space: u32, space_needed: u32) -> u32 {
+    let sizes = filesystem.sizes();
+    let root_size = sizes[ROOT];
+    let delete_size_needed = root_size.saturating_sub(disk_space - space_needed);
+
+    sizes.into_iter()
+        .filter(|&size| size >= delete_size_needed)
+        .min()
+        .unwrap_or(root_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_on_through_the_issues() {
+        let filesystem = parse_input("$ cd ..\n$ ls\n10 a\n$ ls\n10 a").unwrap();
+        assert_eq!(filesystem.sizes(), [20]);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::SplitWhitespace;
+use aoc_core::{lines, Line, ParseError};
+
+use crate::{FileSystem, ROOT};
+
+/// Something in a terminal output that the device could not have printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// `cd ..` in the root folder, which stays there.
+    UpFromRoot { line: usize },
+    /// `ls` in a folder already listed on the line `first_line`.
+    RepeatedListing { line: usize, folder: String, first_line: usize },
+    /// A folder or a file printed after a command other than `ls`.
+    OutsideListing { line: usize },
+}
+
+impl Issue {
+    pub fn line(&self) -> usize {
+        match self {
+            Issue::UpFromRoot { line } | Issue::RepeatedListing { line, .. } | Issue::OutsideListing { line } => *line,
+        }
+    }
+
+    fn reason(&self) -> String {
+        match self {
+            Issue::UpFromRoot { .. } => "cannot go up from the root folder".to_string(),
+            Issue::RepeatedListing { folder, first_line, .. } =>
+                format!("{folder} was already listed on line {first_line}"),
+            Issue::OutsideListing { .. } => "entry printed outside of a listing".to_string(),
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line(), self.reason())
+    }
+}
+
+impl Error for Issue {}
+
+impl From<Issue> for ParseError {
+    fn from(issue: Issue) -> Self {
+        ParseError::Invalid { line: issue.line(), reason: issue.reason() }
+    }
+}
+
+/// How the files of a folder listed several times are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Listings {
+    /// Every listing adds its files, so a file listed twice counts twice.
+    Sum,
+    /// A file listed again replaces the one of the same name, taking its size.
+    Reconcile,
+}
+
+/// A terminal output read despite its issues.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    pub filesystem: FileSystem,
+    /// Issues in the order of their lines.
+    pub issues: Vec<Issue>,
+}
+
+struct Reader {
+    filesystem: FileSystem,
+    issues: Vec<Issue>,
+    listings: Listings,
+    current_folder: usize,
+    /// Whether the last command was `ls`.
+    listing: bool,
+    /// Line of the first listing of each folder.
+    listed: HashMap<usize, usize>,
+}
+
+impl Reader {
+    fn command(&mut self, line: &Line, mut words: SplitWhitespace) -> Result<(), ParseError> {
+        self.listing = false;
+        match line.field(words.next(), "command")? {
+            "cd" => match line.field(words.next(), "folder name")? {
+                ".." => match self.filesystem.folder(self.current_folder).parent {
+                    Some(parent) => self.current_folder = parent,
+                    None => self.issues.push(Issue::UpFromRoot { line: line.number }),
+                },
+                "/" => self.current_folder = ROOT,
+                "." => (),
+                name => self.current_folder = self.filesystem.get_or_create_folder(self.current_folder, name),
+            },
+            "ls" => {
+                self.listing = true;
+                if let Some(&first_line) = self.listed.get(&self.current_folder) {
+                    let folder = self.filesystem.path(self.current_folder);
+                    self.issues.push(Issue::RepeatedListing { line: line.number, folder, first_line });
+                } else {
+                    self.listed.insert(self.current_folder, line.number);
+                }
+            }
+            command => return Err(line.unexpected(command)),
+        }
+        Ok(())
+    }
+
+    fn entry(&mut self, line: &Line, kind: &str, mut words: SplitWhitespace) -> Result<(), ParseError> {
+        if !self.listing {
+            self.issues.push(Issue::OutsideListing { line: line.number });
+        }
+        match kind {
+            "dir" => {
+                self.filesystem.get_or_create_folder(self.current_folder, line.field(words.next(), "folder name")?);
+            }
+            number => {
+                let size = line.parse(number)?;
+                let name = line.field(words.next(), "file name")?;
+                match self.listings {
+                    Listings::Sum => self.filesystem.add_file(self.current_folder, name, size),
+                    Listings::Reconcile => self.filesystem.set_file(self.current_folder, name, size),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the terminal output `input`, going on after the issues found in it.
+/// Fails only on lines that cannot be read at all.
+pub fn read_transcript(input: &str, listings: Listings) -> Result<Transcript, ParseError> {
+    let mut reader = Reader {
+        filesystem: FileSystem::default(),
+        issues: vec![],
+        listings,
+        current_folder: ROOT,
+        listing: false,
+        listed: HashMap::new(),
+    };
+
+    for line in lines(input) {
+        let mut words = line.text.split_whitespace();
+        match line.field(words.next(), "command or listing")? {
+            "$" => reader.command(&line, words)?,
+            kind => reader.entry(&line, kind, words)?,
+        }
+    }
+
+    Ok(Transcript { filesystem: reader.filesystem, issues: reader.issues })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RELISTED: &str = "\
+$ cd /
+$ ls
+dir a
+10 b
+$ cd a
+$ ls
+5 c
+$ cd ..
+$ ls
+dir a
+12 b";
+
+    #[test]
+    fn example_has_no_issue() {
+        let transcript = read_transcript(include_str!("../test.txt"), Listings::Sum).unwrap();
+        assert!(transcript.issues.is_empty());
+        assert_eq!(transcript.filesystem.sizes()[ROOT], 48381165);
+    }
+
+    #[test]
+    fn up_from_root_stays_there() {
+        let transcript = read_transcript("$ cd /\n$ cd ..\n$ ls\n1 a", Listings::Sum).unwrap();
+        assert_eq!(transcript.issues, [Issue::UpFromRoot { line: 2 }]);
+        assert_eq!(transcript.filesystem.folder(ROOT).files.len(), 1);
+    }
+
+    #[test]
+    fn repeated_listing() {
+        let transcript = read_transcript(RELISTED, Listings::Sum).unwrap();
+        let issue = Issue::RepeatedListing { line: 9, folder: "/".to_string(), first_line: 2 };
+        assert_eq!(issue.to_string(), "line 9: / was already listed on line 2");
+        assert_eq!(transcript.issues, [issue]);
+    }
+
+    #[test]
+    fn outside_listing() {
+        let transcript = read_transcript("$ cd /\n1 a\n$ ls\n2 b\n$ cd x\n3 c", Listings::Sum).unwrap();
+        assert_eq!(transcript.issues, [Issue::OutsideListing { line: 2 }, Issue::OutsideListing { line: 6 }]);
+        assert_eq!(transcript.filesystem.sizes(), [6, 3]);
+    }
+
+    #[test]
+    fn reconcile_replaces_files_listed_again() {
+        let sum = read_transcript(RELISTED, Listings::Sum).unwrap();
+        assert_eq!(sum.filesystem.sizes(), [27, 5]);
+        let reconcile = read_transcript(RELISTED, Listings::Reconcile).unwrap();
+        assert_eq!(reconcile.filesystem.sizes(), [17, 5]);
+        assert_eq!(reconcile.issues, sum.issues);
+        assert_eq!(reconcile.filesystem.folder(ROOT).folders.len(), 1);
+    }
+
+    #[test]
+    fn issues_become_parse_errors() {
+        let error = ParseError::from(Issue::UpFromRoot { line: 3 });
+        assert_eq!(error.to_string(), "line 3: cannot go up from the root folder");
+    }
+
+    #[test]
+    fn fails_on_unreadable_lines() {
+        assert!(matches!(read_transcript("$ rm a", Listings::Sum), Err(ParseError::UnexpectedToken { line: 1, column: 3, .. })));
+        assert!(matches!(read_transcript("$ cd", Listings::Sum), Err(ParseError::MissingField { line: 1, field: "folder name", .. })));
+        assert!(matches!(read_transcript("$ ls\nx a", Listings::Sum), Err(ParseError::UnexpectedToken { line: 2, column: 1, .. })));
+    }
+}
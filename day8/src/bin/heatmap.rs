@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{read_input, DEFAULT_INPUT};
+use clap::Parser;
+use day8::{parse_input, survey};
+
+/// Shades from the lowest scenic score to the highest.
+const SHADES: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// Draws which trees are visible from outside the forest, then a heatmap of
+/// their scenic scores.
+#[derive(Parser)]
+struct Args {
+    /// Height of each tree, a row per line
+    #[arg(default_value = DEFAULT_INPUT)]
+    input: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let input = read_input(&args.input);
+    let tree_matrix = match parse_input(&input) {
+        Ok(tree_matrix) => tree_matrix,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let survey = survey(&tree_matrix);
+    println!("Visible trees:\n{}\n", survey.visible.render(|&visible| if visible {'#'} else {'.'}));
+
+    // Scores span orders of magnitude, so the shades follow their logarithm
+    let highest = survey.scenic.iter().copied().max().unwrap_or(0);
+    let shade = |&score: &u32| {
+        let level = (score as f64).ln_1p() / (highest as f64).ln_1p().max(f64::MIN_POSITIVE);
+        SHADES[(level * (SHADES.len() - 1) as f64).round() as usize]
+    };
+    println!("Scenic scores, up to {highest}:\n{}", survey.scenic.render(shade));
+    ExitCode::SUCCESS
+}
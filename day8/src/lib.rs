@@ -0,0 +1,137 @@
+use aoc_core::{Grid, ParseError, Pos, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(tree_matrix: &Self::Input) -> u32 {
+        get_visible_trees(tree_matrix)
+    }
+
+    fn part2(tree_matrix: &Self::Input) -> u32 {
+        highest_scenic(tree_matrix)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |ch| ch.to_digit(10).map(|height| height as u8))
+}
+
+/// What every tree of the forest sees, a cell per tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Survey {
+    /// Whether the tree is visible from outside the forest.
+    pub visible: Grid<bool>,
+    /// Product of the viewing distances of the tree in the 4 directions.
+    pub scenic: Grid<u32>,
+}
+
+/// Surveys the forest `tree_matrix` in O(n²), looking along every row and
+/// column from both ends.
+pub fn survey(tree_matrix: &Grid<u8>) -> Survey {
+    let mut survey = Survey {
+        visible: Grid::new(tree_matrix.width(), tree_matrix.height(), false),
+        scenic: Grid::new(tree_matrix.width(), tree_matrix.height(), 1),
+    };
+
+    let rows = (0..tree_matrix.height())
+        .map(|row| (0..tree_matrix.width()).map(|column| Pos::new(row, column)).collect::<Vec<_>>());
+    let columns = (0..tree_matrix.width())
+        .map(|column| (0..tree_matrix.height()).map(|row| Pos::new(row, column)).collect::<Vec<_>>());
+    for mut line in rows.chain(columns) {
+        look_back(tree_matrix, &line, &mut survey);
+        line.reverse();
+        look_back(tree_matrix, &line, &mut survey);
+    }
+
+    survey
+}
+
+/// Updates `survey` with what each tree of `line` sees looking towards the
+/// start of the line.
+fn look_back(tree_matrix: &Grid<u8>, line: &[Pos], survey: &mut Survey) {
+    // Indices of the trees that can still block the view, from the tallest to
+    // the shortest: a tree hides the shorter ones before it from the next trees
+    let mut blocking: Vec<usize> = vec![];
+
+    for (idx, &pos) in line.iter().enumerate() {
+        let height = tree_matrix[pos];
+        while blocking.last().is_some_and(|&last| tree_matrix[line[last]] < height) {
+            blocking.pop();
+        }
+        let distance = match blocking.last() {
+            Some(&last) => idx - last,
+            // Only shorter trees up to the edge
+            None => {
+                survey.visible[pos] = true;
+                idx
+            }
+        };
+        survey.scenic[pos] *= distance as u32;
+        blocking.push(idx);
+    }
+}
+
+pub fn get_visible_trees(tree_matrix: &Grid<u8>) -> u32 {
+    survey(tree_matrix).visible.iter().filter(|&&visible| visible).count() as u32
+}
+
+pub fn highest_scenic(tree_matrix: &Grid<u8>) -> u32 {
+    survey(tree_matrix).scenic.iter().copied().max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scenic score of the tree at `pos`, looking at every tree in each direction.
+    fn naive_scenic(tree_matrix: &Grid<u8>, pos: Pos) -> u32 {
+        aoc_core::Offset::ORTHOGONAL.into_iter()
+            .map(|offset| {
+                let trees: Vec<Pos> = tree_matrix.ray(pos, offset).collect();
+                let blocked = trees.iter().position(|&tree| tree_matrix[tree] >= tree_matrix[pos]);
+                blocked.map_or(trees.len(), |idx| idx + 1) as u32
+            })
+            .product()
+    }
+
+    #[test]
+    fn surveys_the_example() {
+        let tree_matrix = parse_input(include_str!("../test.txt")).unwrap();
+        let survey = survey(&tree_matrix);
+        assert_eq!(survey.visible.render(|&visible| if visible {'#'} else {'.'}).to_string(), "\
+#####
+###.#
+##.##
+#.#.#
+#####");
+        assert_eq!(survey.scenic[Pos::new(1, 2)], 4);
+        assert_eq!(survey.scenic[Pos::new(3, 2)], 8);
+        assert_eq!(survey.scenic, Grid::from_fn(5, 5, |pos| naive_scenic(&tree_matrix, pos)));
+        assert_eq!((get_visible_trees(&tree_matrix), highest_scenic(&tree_matrix)), (21, 8));
+    }
+
+    #[test]
+    fn equal_heights_block_the_view() {
+        let tree_matrix = parse_input("111\n111\n111").unwrap();
+        let survey = survey(&tree_matrix);
+        assert!(!survey.visible[Pos::new(1, 1)]);
+        // The center sees a tree each way, the edges see nothing one way
+        assert_eq!(survey.scenic, Grid::from_fn(3, 3, |pos| (pos == Pos::new(1, 1)) as u32));
+    }
+
+    #[test]
+    fn empty_and_single_tree_forests() {
+        let empty = parse_input("").unwrap();
+        assert_eq!((get_visible_trees(&empty), highest_scenic(&empty)), (0, 0));
+        let single = parse_input("5").unwrap();
+        assert_eq!((get_visible_trees(&single), highest_scenic(&single)), (1, 0));
+    }
+}
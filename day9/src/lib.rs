@@ -0,0 +1,178 @@
+use core::panic;
+use aoc_core::{lines, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(char, u8)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(actions: &Self::Input) -> usize {
+        get_visited_positions(actions)
+    }
+
+    fn part2(actions: &Self::Input) -> usize {
+        get_visited_positions_v2(actions)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(char, u8)>, ParseError> {
+    let mut actions: Vec<(char, u8)> = Vec::new();
+    let mut direction_steps;
+
+    for line in lines(input) {
+        direction_steps = line.text.split_whitespace();
+        let direction = line.field(direction_steps.next(), "direction")?;
+        if !["U", "D", "R", "L"].contains(&direction) {return Err(line.unexpected(direction));}
+        actions.push(
+            (line.parse(direction)?, 
+            line.parse_field(direction_steps.next(), "steps")?)
+        );
+    }
+
+    Ok(actions)
+}
+
+fn move_head(
+    mut head_coords: (i32, i32),
+    mut tail_coords: (i32, i32),
+    direction:char, 
+    visited_coords:&mut HashSet<(i32,i32)>) 
+    -> ((i32, i32), (i32, i32)) {
+
+    match direction {
+        'U' => head_coords.1 += 1,
+        'D' => head_coords.1 -= 1,
+        'R' => head_coords.0 += 1,
+        'L' => head_coords.0 -= 1,
+        _ => panic!("The options are only U, D, R and L.")
+    }
+    
+    let dist_x = head_coords.0 - tail_coords.0;
+    let dist_y = head_coords.1 - tail_coords.1;
+
+    if dist_x.abs() == 2 {
+        tail_coords.1 += dist_y;
+        if dist_x == 2 {
+            tail_coords.0 += 1
+        }
+        else {
+            tail_coords.0 -= 1
+        }
+    }
+    else if dist_y.abs() == 2 {
+        tail_coords.0 += dist_x;
+        if dist_y == 2 {
+            tail_coords.1 += 1
+        }
+        else {
+            tail_coords.1 -= 1
+        }
+    }
+
+    visited_coords.insert(tail_coords);
+
+    (head_coords, tail_coords)
+
+}
+
+fn move_head_v2(
+    last_coords: (i32,i32),
+    mut current_coords: (i32, i32)
+) -> (i32, i32) {
+
+    
+    let dist_x = last_coords.0 - current_coords.0;
+    let dist_y = last_coords.1 - current_coords.1;
+
+    if dist_x.abs() == 2 && dist_y.abs() == 2 {
+        current_coords.0 += dist_x.clamp(-1, 1);
+        current_coords.1 += dist_y.clamp(-1, 1);
+    }
+    else if dist_x.abs() == 2 {
+        current_coords.1 += dist_y;
+        if dist_x == 2 {
+            current_coords.0 += 1
+        }
+        else {
+            current_coords.0 -= 1
+        }
+    }
+    else if dist_y.abs() == 2 {
+        current_coords.0 += dist_x;
+        if dist_y == 2 {
+            current_coords.1 += 1
+        }
+        else {
+            current_coords.1 -= 1
+        }
+    }
+    
+    current_coords
+
+}
+
+pub fn get_visited_positions(actions: &[(char, u8)]) -> usize {
+
+    let mut visited_coords = HashSet::new();
+    visited_coords.insert((0,0));
+
+    let mut head_coords:(i32, i32) = (0,0);
+    let mut tail_coords:(i32, i32) = (0,0);
+
+    for &(direction, times) in actions {
+        for _ in 0..times {
+        (head_coords, tail_coords) = move_head(
+            head_coords, 
+            tail_coords, 
+            direction, 
+            &mut visited_coords);
+        }
+    }
+
+
+    visited_coords.len()
+    
+}
+
+pub fn get_visited_positions_v2(actions: &[(char, u8)]) -> usize {
+    const ROPE_LEN:usize = 10;
+
+    let mut visited_coords = HashSet::new();
+    visited_coords.insert((0,0));
+
+    let mut rope_coords:[(i32,i32);10] = [(0,0); ROPE_LEN];
+
+    for &(direction, times) in actions {
+        for _ in 0..times {
+            // Move head
+            match direction {
+                'U' => rope_coords[0].1 -= 1,
+                'D' => rope_coords[0].1 += 1,
+                'R' => rope_coords[0].0 += 1,
+                'L' => rope_coords[0].0 -= 1,
+                _ => panic!("The options are only U, D, R and L.")
+            }
+
+            for i in 1..rope_coords.len() {
+                rope_coords[i] = move_head_v2(
+                rope_coords[i-1], 
+                rope_coords[i]);
+            }
+
+
+
+            visited_coords.insert(rope_coords[ROPE_LEN-1]);
+        }
+    }
+
+
+    visited_coords.len()
+    
+}